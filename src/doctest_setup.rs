@@ -38,11 +38,12 @@ cfg_if! {
             connection.execute("CREATE TABLE shapes (
                 id SERIAL PRIMARY KEY,
                 drawing_id INTEGER NOT NULL,
-                centroid POINT
+                centroid POINT,
+                bounds BOX
             )").unwrap();
-            connection.execute("INSERT INTO shapes (drawing_id, centroid) VALUES
-                               (1, point '(0, 0)'),
-                               (2, point '(1,2)')").unwrap();
+            connection.execute("INSERT INTO shapes (drawing_id, centroid, bounds) VALUES
+                               (1, point '(0, 0)', box '((-1,-1),(1,1))'),
+                               (2, point '(1,2)', box '((0,1),(2,3))')").unwrap();
 
 
             connection
@@ -77,11 +78,12 @@ mod schema {
 
     table! {
         use diesel::sql_types::*;
-        use diesel_geometry::sql_types::Point;
+        use diesel_geometry::sql_types::{Box, Point};
         shapes {
             id -> Integer,
             drawing_id -> Integer,
             centroid -> Point,
+            bounds -> Box,
         }
     }
 
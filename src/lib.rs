@@ -98,6 +98,12 @@
 //! To find all the Rust types which can be used with a given SQL type,
 //! see the documentation for that SQL type.
 //!
+//! Every geometric SQL type can also be used as the element of Diesel's
+//! [`Array`] type. For example a `point[]` column is declared as `Array<Point>`
+//! and maps to `Vec<PgPoint>`, or to `Vec<Option<PgPoint>>` when declared as
+//! `Array<Nullable<Point>>`.
+//!
+//! [`Array`]: ::diesel::sql_types::Array
 //! [`diesel_geometry::sql_types::Point`]: ::diesel_geometry::sql_types::Point
 //! [`ToSql`]: ::diesel::serialize::ToSql
 //! [`FromSql`]: ::diesel::deserialize::FromSql
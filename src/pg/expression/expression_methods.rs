@@ -1,8 +1,16 @@
-use diesel::expression::{AsExpression, Expression};
-use diesel::pg::expression::operators::IsContainedBy;
+use diesel::dsl::{all, any};
+use diesel::expression::{ops, AsExpression, Expression};
+use diesel::pg::expression::array_comparison::{All, Any};
+use diesel::pg::expression::operators::{Contains, IsContainedBy, OverlapsWith};
+use diesel::sql_types::{self as diesel_sql_types, Array, Nullable};
 
-use super::operators::SameAs;
-use sql_types::{self, Circle, Point};
+use super::operators::{
+    Center, ClosestPoint, Distance, DoesNotExtendAbove, DoesNotExtendBelow, DoesNotExtendLeftOf,
+    DoesNotExtendRightOf, Intersection, Intersects, IsAbove, IsBelow, IsHorizontal,
+    IsHorizontalWith, IsParallelTo, IsPerpendicularTo, IsStrictlyAbove, IsStrictlyBelow,
+    IsStrictlyLeftOf, IsStrictlyRightOf, IsVertical, IsVerticalWith, Length, NumPoints, SameAs,
+};
+use sql_types::{self, Circle, Line, Lseg, Path, Point, Polygon};
 
 pub trait PgSameAsExpressionMethods<ST>: Expression + Sized {
     /// Creates a PostgresSQL `~=`  expression.
@@ -40,6 +48,45 @@ pub trait PgSameAsExpressionMethods<ST>: Expression + Sized {
 impl<T: Expression<SqlType = Point>> PgSameAsExpressionMethods<Point> for T {}
 impl<T: Expression<SqlType = sql_types::Box>> PgSameAsExpressionMethods<sql_types::Box> for T {}
 impl<T: Expression<SqlType = Circle>> PgSameAsExpressionMethods<Circle> for T {}
+impl<T: Expression<SqlType = Polygon>> PgSameAsExpressionMethods<Polygon> for T {}
+
+pub trait PgOverlapsWithExpressionMethods<ST>: Expression + Sized {
+    /// Creates a PostgreSQL `&&` expression.
+    ///
+    /// Postgres only defines this operator between two boxes, two circles or two polygons.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgBox, PgPoint};
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let viewport = PgBox(PgPoint(1.5, 2.5), PgPoint(5.0, 5.0));
+    /// let visible_drawing_ids = shapes
+    ///     .select(drawing_id)
+    ///     .filter(bounds.overlaps_with(viewport))
+    ///     .load(&connection);
+    /// assert_eq!(Ok(vec![2]), visible_drawing_ids);
+    /// # }
+    /// ```
+    fn overlaps_with<T>(self, other: T) -> OverlapsWith<Self, T::Expression>
+    where
+        T: AsExpression<ST>,
+    {
+        OverlapsWith::new(self, other.as_expression())
+    }
+}
+
+impl<T: Expression<SqlType = sql_types::Box>> PgOverlapsWithExpressionMethods<sql_types::Box>
+    for T
+{}
+impl<T: Expression<SqlType = Circle>> PgOverlapsWithExpressionMethods<Circle> for T {}
+impl<T: Expression<SqlType = Polygon>> PgOverlapsWithExpressionMethods<Polygon> for T {}
 
 pub trait PgIsContainedByExpressionMethods<ST>: Expression + Sized {
     /// Creates a PostgresSQL `<@` expression.
@@ -82,12 +129,21 @@ pub trait CanBeContainedByCircle {}
 impl CanBeContainedByCircle for Point {}
 impl CanBeContainedByCircle for Circle {}
 
-// A Box can contain a Point, Circle, and a Box
+// A Box can contain a Point or a Box. Postgres has no operator checking if a Box contains a
+// Circle.
 pub trait CanBeContainedByBox {}
 impl CanBeContainedByBox for Point {}
-impl CanBeContainedByBox for Circle {}
 impl CanBeContainedByBox for sql_types::Box {}
 
+// A Polygon can contain a Point or a Polygon
+pub trait CanBeContainedByPolygon {}
+impl CanBeContainedByPolygon for Point {}
+impl CanBeContainedByPolygon for Polygon {}
+
+// A Path can only contain a Point
+pub trait CanBeContainedByPath {}
+impl CanBeContainedByPath for Point {}
+
 impl<T> PgIsContainedByExpressionMethods<Circle> for T
 where
     T: Expression,
@@ -98,3 +154,1258 @@ where
     T: Expression,
     T::SqlType: CanBeContainedByBox,
 {}
+impl<T> PgIsContainedByExpressionMethods<Polygon> for T
+where
+    T: Expression,
+    T::SqlType: CanBeContainedByPolygon,
+{}
+impl<T> PgIsContainedByExpressionMethods<Path> for T
+where
+    T: Expression,
+    T::SqlType: CanBeContainedByPath,
+{}
+
+pub trait PgContainsExpressionMethods<ST>: Expression + Sized {
+    /// Creates a PostgreSQL `@>` expression.
+    ///
+    /// This is the mirror of
+    /// [`is_contained_by`](PgIsContainedByExpressionMethods::is_contained_by), and accepts the
+    /// same pairs of geometric types.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgBox, PgPoint};
+    /// # use diesel_geometry::sql_types;
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let found_drawing_id = shapes
+    ///     .select(drawing_id)
+    ///     .filter(bounds.contains(PgPoint(1.5, 2.5).into_sql::<sql_types::Point>()))
+    ///     .first(&connection);
+    /// assert_eq!(Ok(2), found_drawing_id);
+    ///
+    /// let found_drawing_ids = shapes
+    ///     .select(drawing_id)
+    ///     .filter(bounds.contains(
+    ///         PgBox(PgPoint(0.0, 0.0), PgPoint(0.5, 0.5)).into_sql::<sql_types::Box>()
+    ///     ))
+    ///     .load(&connection);
+    /// assert_eq!(Ok(vec![1]), found_drawing_ids);
+    /// # }
+    /// ```
+    fn contains<T>(self, other: T) -> Contains<Self, T::Expression>
+    where
+        T: AsExpression<ST>,
+    {
+        Contains::new(self, other.as_expression())
+    }
+}
+
+/// Implemented by the geometric SQL types which can appear on the left side of `@>` with `ST` on
+/// the right side. These are the same pairs allowed by `<@` in the opposite order.
+pub trait CanContain<ST> {}
+impl<ST: CanBeContainedByCircle> CanContain<ST> for Circle {}
+impl<ST: CanBeContainedByBox> CanContain<ST> for sql_types::Box {}
+impl<ST: CanBeContainedByPolygon> CanContain<ST> for Polygon {}
+impl<ST: CanBeContainedByPath> CanContain<ST> for Path {}
+
+impl<T, ST> PgContainsExpressionMethods<ST> for T
+where
+    T: Expression,
+    T::SqlType: CanContain<ST>,
+{}
+
+pub trait PgDistanceExpressionMethods<ST>: Expression + Sized {
+    /// Creates a PostgreSQL `<->` expression, which is the distance between two geometric
+    /// values as a `Double`.
+    ///
+    /// When used in an `ORDER BY` clause on a column with a GiST index, Postgres can use the index
+    /// to find the nearest neighbours of `other`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::PgPoint;
+    /// # use diesel_geometry::sql_types;
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let target = PgPoint(1.0, 1.5).into_sql::<sql_types::Point>();
+    /// let nearest = shapes
+    ///     .select((drawing_id, centroid.distance(target)))
+    ///     .order(centroid.distance(target))
+    ///     .first(&connection);
+    /// assert_eq!(Ok((2, 0.5)), nearest);
+    ///
+    /// let inside_bounds = shapes
+    ///     .select(drawing_id)
+    ///     .filter(bounds.distance(target).eq(0.0))
+    ///     .order(drawing_id)
+    ///     .load(&connection);
+    /// assert_eq!(Ok(vec![2]), inside_bounds);
+    /// # }
+    /// ```
+    fn distance<T>(self, other: T) -> Distance<Self, T::Expression>
+    where
+        T: AsExpression<ST>,
+    {
+        Distance::new(self, other.as_expression())
+    }
+}
+
+/// Implemented by the geometric SQL types which Postgres can measure the distance from, with `ST`
+/// on the right side of `<->`.
+pub trait CanMeasureDistanceTo<ST> {}
+impl CanMeasureDistanceTo<Point> for Point {}
+impl CanMeasureDistanceTo<Lseg> for Point {}
+impl CanMeasureDistanceTo<Path> for Point {}
+impl CanMeasureDistanceTo<sql_types::Box> for Point {}
+impl CanMeasureDistanceTo<Polygon> for Point {}
+impl CanMeasureDistanceTo<Line> for Point {}
+impl CanMeasureDistanceTo<Circle> for Point {}
+impl CanMeasureDistanceTo<Point> for Lseg {}
+impl CanMeasureDistanceTo<Lseg> for Lseg {}
+impl CanMeasureDistanceTo<sql_types::Box> for Lseg {}
+impl CanMeasureDistanceTo<Line> for Lseg {}
+impl CanMeasureDistanceTo<Point> for Path {}
+impl CanMeasureDistanceTo<Path> for Path {}
+impl CanMeasureDistanceTo<Point> for sql_types::Box {}
+impl CanMeasureDistanceTo<Lseg> for sql_types::Box {}
+impl CanMeasureDistanceTo<sql_types::Box> for sql_types::Box {}
+impl CanMeasureDistanceTo<Point> for Polygon {}
+impl CanMeasureDistanceTo<Polygon> for Polygon {}
+impl CanMeasureDistanceTo<Circle> for Polygon {}
+impl CanMeasureDistanceTo<Point> for Line {}
+impl CanMeasureDistanceTo<Lseg> for Line {}
+impl CanMeasureDistanceTo<Line> for Line {}
+impl CanMeasureDistanceTo<Point> for Circle {}
+impl CanMeasureDistanceTo<Polygon> for Circle {}
+impl CanMeasureDistanceTo<Circle> for Circle {}
+
+impl<T, ST> PgDistanceExpressionMethods<ST> for T
+where
+    T: Expression,
+    T::SqlType: CanMeasureDistanceTo<ST>,
+{}
+
+pub trait PgIntersectionExpressionMethods<ST>: Expression + Sized {
+    /// Creates a PostgreSQL `?#` expression, which is true if the two geometric values intersect.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgBox, PgPoint};
+    /// # use diesel_geometry::sql_types;
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let window = PgBox(PgPoint(1.5, 2.5), PgPoint(5.0, 5.0)).into_sql::<sql_types::Box>();
+    /// let crossing = shapes
+    ///     .select(drawing_id)
+    ///     .filter(bounds.intersects(window))
+    ///     .load(&connection);
+    /// assert_eq!(Ok(vec![2]), crossing);
+    /// # }
+    /// ```
+    fn intersects<T>(self, other: T) -> Intersects<Self, T::Expression>
+    where
+        T: AsExpression<ST>,
+    {
+        Intersects::new(self, other.as_expression())
+    }
+
+    /// Creates a PostgreSQL `#` expression, which is the intersection of the two geometric
+    /// values: a `Box` for two boxes, or a `Point` for two line segments or two lines. Postgres
+    /// returns `NULL` if they do not intersect.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgBox, PgLineSegment, PgPoint};
+    /// # use diesel_geometry::sql_types;
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let window = PgBox(PgPoint(0.0, 0.0), PgPoint(5.0, 5.0)).into_sql::<sql_types::Box>();
+    /// let clipped = shapes
+    ///     .select(bounds.intersection(window))
+    ///     .filter(drawing_id.eq(1))
+    ///     .first(&connection);
+    /// assert_eq!(Ok(Some(PgBox(PgPoint(0.0, 0.0), PgPoint(1.0, 1.0)))), clipped);
+    ///
+    /// let rising = PgLineSegment(PgPoint(0.0, 0.0), PgPoint(2.0, 2.0));
+    /// let falling = PgLineSegment(PgPoint(0.0, 2.0), PgPoint(2.0, 0.0));
+    /// let crossing = diesel::select(
+    ///     rising
+    ///         .into_sql::<sql_types::Lseg>()
+    ///         .intersection(falling.into_sql::<sql_types::Lseg>()),
+    /// ).get_result(&connection);
+    /// assert_eq!(Ok(Some(PgPoint(1.0, 1.0))), crossing);
+    /// # }
+    /// ```
+    fn intersection<T>(self, other: T) -> Intersection<Self, T::Expression>
+    where
+        Self::SqlType: HasIntersectionWith<ST>,
+        T: AsExpression<ST>,
+    {
+        Intersection::new(self, other.as_expression())
+    }
+}
+
+/// Implemented by the geometric SQL types which Postgres can test for intersection with `ST`,
+/// with `ST` on the right side of `?#`.
+pub trait CanIntersect<ST> {}
+impl CanIntersect<Lseg> for Lseg {}
+impl CanIntersect<sql_types::Box> for Lseg {}
+impl CanIntersect<Line> for Lseg {}
+impl CanIntersect<Path> for Path {}
+impl CanIntersect<sql_types::Box> for sql_types::Box {}
+impl CanIntersect<sql_types::Box> for Line {}
+impl CanIntersect<Line> for Line {}
+
+/// Implemented by the geometric SQL types which Postgres can compute the intersection of with
+/// `ST`, with `ST` on the right side of `#`. `Output` is the SQL type of the intersection.
+pub trait HasIntersectionWith<ST> {
+    type Output;
+}
+
+impl HasIntersectionWith<Lseg> for Lseg {
+    type Output = Nullable<Point>;
+}
+
+impl HasIntersectionWith<sql_types::Box> for sql_types::Box {
+    type Output = Nullable<sql_types::Box>;
+}
+
+impl HasIntersectionWith<Line> for Line {
+    type Output = Nullable<Point>;
+}
+
+impl<T, ST> PgIntersectionExpressionMethods<ST> for T
+where
+    T: Expression,
+    T::SqlType: CanIntersect<ST>,
+{}
+
+pub trait PgClosestPointExpressionMethods<ST>: Expression + Sized {
+    /// Creates a PostgreSQL `##` expression, which is the point on `other` closest to this
+    /// value.
+    ///
+    /// The result is a `Point`, except for a line segment or line with a line segment, which
+    /// Postgres leaves `NULL` when the two are parallel.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgBox, PgLineSegment, PgPoint};
+    /// # use diesel_geometry::sql_types;
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let road = PgLineSegment(PgPoint(-2.0, 1.0), PgPoint(2.0, 1.0));
+    /// let road = road.into_sql::<sql_types::Lseg>();
+    /// let snapped = shapes
+    ///     .select(centroid.closest_point_to(road))
+    ///     .order(drawing_id)
+    ///     .load(&connection);
+    /// assert_eq!(Ok(vec![PgPoint(0.0, 1.0), PgPoint(1.0, 1.0)]), snapped);
+    ///
+    /// let fence = PgBox(PgPoint(3.0, 0.0), PgPoint(4.0, 4.0)).into_sql::<sql_types::Box>();
+    /// let gate = shapes
+    ///     .select(centroid.closest_point_to(fence))
+    ///     .filter(drawing_id.eq(2))
+    ///     .first(&connection);
+    /// assert_eq!(Ok(PgPoint(3.0, 2.0)), gate);
+    /// # }
+    /// ```
+    fn closest_point_to<T>(self, other: T) -> ClosestPoint<Self, T::Expression>
+    where
+        T: AsExpression<ST>,
+    {
+        ClosestPoint::new(self, other.as_expression())
+    }
+}
+
+/// Implemented by the geometric SQL types which Postgres can find the closest point on `ST` to,
+/// with `ST` on the right side of `##`. `Output` is the SQL type of that point.
+pub trait HasClosestPointTo<ST> {
+    type Output;
+}
+
+impl HasClosestPointTo<Lseg> for Point {
+    type Output = Point;
+}
+
+impl HasClosestPointTo<sql_types::Box> for Point {
+    type Output = Point;
+}
+
+impl HasClosestPointTo<Line> for Point {
+    type Output = Point;
+}
+
+impl HasClosestPointTo<sql_types::Box> for Lseg {
+    type Output = Point;
+}
+
+impl HasClosestPointTo<Lseg> for Lseg {
+    type Output = Nullable<Point>;
+}
+
+impl HasClosestPointTo<Lseg> for Line {
+    type Output = Nullable<Point>;
+}
+
+impl<T, ST> PgClosestPointExpressionMethods<ST> for T
+where
+    T: Expression,
+    T::SqlType: HasClosestPointTo<ST>,
+{}
+
+// The `is_` methods build expressions, so they have to take `self` by value.
+#[allow(clippy::wrong_self_convention)]
+pub trait PgOrientationExpressionMethods<ST>: Expression + Sized {
+    /// Creates a PostgreSQL `?||` expression.
+    ///
+    /// True if this line segment or line is parallel to `other`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgLineSegment, PgPoint};
+    /// # use diesel_geometry::sql_types;
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// let wall = PgLineSegment(PgPoint(0.0, 0.0), PgPoint(4.0, 0.0));
+    /// let other_wall = PgLineSegment(PgPoint(0.0, 3.0), PgPoint(2.0, 3.0));
+    /// let wall = wall.into_sql::<sql_types::Lseg>();
+    /// let parallel = diesel::select(wall.is_parallel_to(other_wall)).get_result(&connection);
+    /// assert_eq!(Ok(true), parallel);
+    /// # }
+    /// ```
+    fn is_parallel_to<T>(self, other: T) -> IsParallelTo<Self, T::Expression>
+    where
+        ST: HasDirection,
+        T: AsExpression<ST>,
+    {
+        IsParallelTo::new(self, other.as_expression())
+    }
+
+    /// Creates a PostgreSQL `?-|` expression.
+    ///
+    /// True if this line segment or line is perpendicular to `other`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgLineSegment, PgPoint};
+    /// # use diesel_geometry::sql_types;
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// let wall = PgLineSegment(PgPoint(0.0, 0.0), PgPoint(4.0, 0.0));
+    /// let other_wall = PgLineSegment(PgPoint(1.0, -1.0), PgPoint(1.0, 1.0));
+    /// let wall = wall.into_sql::<sql_types::Lseg>();
+    /// let perpendicular =
+    ///     diesel::select(wall.is_perpendicular_to(other_wall)).get_result(&connection);
+    /// assert_eq!(Ok(true), perpendicular);
+    /// # }
+    /// ```
+    fn is_perpendicular_to<T>(self, other: T) -> IsPerpendicularTo<Self, T::Expression>
+    where
+        ST: HasDirection,
+        T: AsExpression<ST>,
+    {
+        IsPerpendicularTo::new(self, other.as_expression())
+    }
+
+    /// Creates a PostgreSQL `?-` expression.
+    ///
+    /// True if this point and `other` lie on the same horizontal line.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::PgPoint;
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let level_drawing_ids = shapes
+    ///     .select(drawing_id)
+    ///     .filter(centroid.is_horizontal_with(PgPoint(5.0, 2.0)))
+    ///     .load(&connection);
+    /// assert_eq!(Ok(vec![2]), level_drawing_ids);
+    /// # }
+    /// ```
+    fn is_horizontal_with<T>(self, other: T) -> IsHorizontalWith<Self, T::Expression>
+    where
+        ST: CanBeAligned,
+        T: AsExpression<ST>,
+    {
+        IsHorizontalWith::new(self, other.as_expression())
+    }
+
+    /// Creates a PostgreSQL `?|` expression.
+    ///
+    /// True if this point and `other` lie on the same vertical line.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::PgPoint;
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let aligned_drawing_ids = shapes
+    ///     .select(drawing_id)
+    ///     .filter(centroid.is_vertical_with(PgPoint(0.0, 5.0)))
+    ///     .load(&connection);
+    /// assert_eq!(Ok(vec![1]), aligned_drawing_ids);
+    /// # }
+    /// ```
+    fn is_vertical_with<T>(self, other: T) -> IsVerticalWith<Self, T::Expression>
+    where
+        ST: CanBeAligned,
+        T: AsExpression<ST>,
+    {
+        IsVerticalWith::new(self, other.as_expression())
+    }
+
+    /// Creates a PostgreSQL `?-` expression.
+    ///
+    /// True if this line segment or line is horizontal. This is the unary form of the
+    /// operator; use [`is_horizontal_with`](PgOrientationExpressionMethods::is_horizontal_with)
+    /// to compare two points.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgLineSegment, PgPoint};
+    /// # use diesel_geometry::sql_types;
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// let wall = PgLineSegment(PgPoint(0.0, 2.0), PgPoint(4.0, 2.0));
+    /// let horizontal = diesel::select(wall.into_sql::<sql_types::Lseg>().is_horizontal())
+    ///     .get_result(&connection);
+    /// assert_eq!(Ok(true), horizontal);
+    /// # }
+    /// ```
+    fn is_horizontal(self) -> IsHorizontal<Self>
+    where
+        ST: HasDirection,
+    {
+        IsHorizontal::new(self)
+    }
+
+    /// Creates a PostgreSQL `?|` expression.
+    ///
+    /// True if this line segment or line is vertical. This is the unary form of the
+    /// operator; use [`is_vertical_with`](PgOrientationExpressionMethods::is_vertical_with) to
+    /// compare two points.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgLineSegment, PgPoint};
+    /// # use diesel_geometry::sql_types;
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// let wall = PgLineSegment(PgPoint(0.0, 2.0), PgPoint(4.0, 2.0));
+    /// let vertical = diesel::select(wall.into_sql::<sql_types::Lseg>().is_vertical())
+    ///     .get_result(&connection);
+    /// assert_eq!(Ok(false), vertical);
+    /// # }
+    /// ```
+    fn is_vertical(self) -> IsVertical<Self>
+    where
+        ST: HasDirection,
+    {
+        IsVertical::new(self)
+    }
+}
+
+/// Implemented by the geometric SQL types which Postgres can compare the orientation of.
+pub trait HasOrientation {}
+impl HasOrientation for Point {}
+impl HasOrientation for Lseg {}
+impl HasOrientation for Line {}
+
+/// Implemented by line segments and lines, which Postgres can test for being parallel,
+/// perpendicular, horizontal or vertical.
+pub trait HasDirection: HasOrientation {}
+impl HasDirection for Lseg {}
+impl HasDirection for Line {}
+
+/// Implemented by points, which Postgres can test for being horizontally or vertically aligned.
+pub trait CanBeAligned: HasOrientation {}
+impl CanBeAligned for Point {}
+
+impl<T, ST> PgOrientationExpressionMethods<ST> for T
+where
+    T: Expression<SqlType = ST>,
+    ST: HasOrientation,
+{}
+
+// The `is_` methods build expressions, so they have to take `self` by value.
+#[allow(clippy::wrong_self_convention)]
+pub trait PgPositionExpressionMethods<ST>: Expression + Sized {
+    /// Creates a PostgreSQL `<<` expression.
+    ///
+    /// True if this value is strictly left of `other`, without touching it.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgPoint};
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let found_drawing_ids = shapes
+    ///     .select(drawing_id)
+    ///     .filter(centroid.is_strictly_left_of(PgPoint(0.5, 0.0)))
+    ///     .load(&connection);
+    /// assert_eq!(Ok(vec![1]), found_drawing_ids);
+    /// # }
+    /// ```
+    fn is_strictly_left_of<T>(self, other: T) -> IsStrictlyLeftOf<Self, T::Expression>
+    where
+        T: AsExpression<ST>,
+    {
+        IsStrictlyLeftOf::new(self, other.as_expression())
+    }
+
+    /// Creates a PostgreSQL `>>` expression.
+    ///
+    /// True if this value is strictly right of `other`, without touching it.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgBox, PgPoint};
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let found_drawing_ids = shapes
+    ///     .select(drawing_id)
+    ///     .filter(bounds.is_strictly_right_of(PgBox(PgPoint(-3.0, 0.0), PgPoint(-1.5, 1.0))))
+    ///     .order(drawing_id)
+    ///     .load(&connection);
+    /// assert_eq!(Ok(vec![1, 2]), found_drawing_ids);
+    /// # }
+    /// ```
+    fn is_strictly_right_of<T>(self, other: T) -> IsStrictlyRightOf<Self, T::Expression>
+    where
+        T: AsExpression<ST>,
+    {
+        IsStrictlyRightOf::new(self, other.as_expression())
+    }
+
+    /// Creates a PostgreSQL `<<|` expression.
+    ///
+    /// True if this value is strictly below `other`, without touching it.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgBox, PgPoint};
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let found_drawing_ids = shapes
+    ///     .select(drawing_id)
+    ///     .filter(bounds.is_strictly_below(PgBox(PgPoint(0.0, 2.0), PgPoint(1.0, 4.0))))
+    ///     .load(&connection);
+    /// assert_eq!(Ok(vec![1]), found_drawing_ids);
+    /// # }
+    /// ```
+    fn is_strictly_below<T>(self, other: T) -> IsStrictlyBelow<Self, T::Expression>
+    where
+        T: AsExpression<ST>,
+    {
+        IsStrictlyBelow::new(self, other.as_expression())
+    }
+
+    /// Creates a PostgreSQL `|>>` expression.
+    ///
+    /// True if this value is strictly above `other`, without touching it.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgPoint};
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let found_drawing_ids = shapes
+    ///     .select(drawing_id)
+    ///     .filter(centroid.is_strictly_above(PgPoint(5.0, 1.0)))
+    ///     .load(&connection);
+    /// assert_eq!(Ok(vec![2]), found_drawing_ids);
+    /// # }
+    /// ```
+    fn is_strictly_above<T>(self, other: T) -> IsStrictlyAbove<Self, T::Expression>
+    where
+        T: AsExpression<ST>,
+    {
+        IsStrictlyAbove::new(self, other.as_expression())
+    }
+
+    /// Creates a PostgreSQL `<^` expression.
+    ///
+    /// True if this value is below `other`. Unlike
+    /// [`is_strictly_below`](PgPositionExpressionMethods::is_strictly_below), boxes are allowed
+    /// to touch. Postgres only defines this operator for points and boxes.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgBox, PgPoint};
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let found_drawing_ids = shapes
+    ///     .select(drawing_id)
+    ///     .filter(bounds.is_below(PgBox(PgPoint(0.0, 1.0), PgPoint(1.0, 4.0))))
+    ///     .load(&connection);
+    /// assert_eq!(Ok(vec![1]), found_drawing_ids);
+    /// # }
+    /// ```
+    fn is_below<T>(self, other: T) -> IsBelow<Self, T::Expression>
+    where
+        ST: CanBePositionedVertically,
+        T: AsExpression<ST>,
+    {
+        IsBelow::new(self, other.as_expression())
+    }
+
+    /// Creates a PostgreSQL `>^` expression.
+    ///
+    /// True if this value is above `other`. Unlike
+    /// [`is_strictly_above`](PgPositionExpressionMethods::is_strictly_above), boxes are allowed
+    /// to touch. Postgres only defines this operator for points and boxes.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgBox, PgPoint};
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let found_drawing_ids = shapes
+    ///     .select(drawing_id)
+    ///     .filter(bounds.is_above(PgBox(PgPoint(0.0, -2.0), PgPoint(1.0, 1.0))))
+    ///     .load(&connection);
+    /// assert_eq!(Ok(vec![2]), found_drawing_ids);
+    /// # }
+    /// ```
+    fn is_above<T>(self, other: T) -> IsAbove<Self, T::Expression>
+    where
+        ST: CanBePositionedVertically,
+        T: AsExpression<ST>,
+    {
+        IsAbove::new(self, other.as_expression())
+    }
+}
+
+// Points, Boxes, Polygons and Circles can be compared to a value of the same type with the
+// strict positional operators.
+pub trait CanBeStrictlyPositioned {}
+impl CanBeStrictlyPositioned for Point {}
+impl CanBeStrictlyPositioned for sql_types::Box {}
+impl CanBeStrictlyPositioned for Polygon {}
+impl CanBeStrictlyPositioned for Circle {}
+
+// Only Points and Boxes have the `<^` and `>^` operators
+pub trait CanBePositionedVertically {}
+impl CanBePositionedVertically for Point {}
+impl CanBePositionedVertically for sql_types::Box {}
+
+impl<T, ST> PgPositionExpressionMethods<ST> for T
+where
+    T: Expression<SqlType = ST>,
+    ST: CanBeStrictlyPositioned,
+{}
+
+pub trait PgExtentExpressionMethods<ST>: Expression + Sized {
+    /// Creates a PostgreSQL `&<` expression.
+    ///
+    /// True if the right edge of this value is not right of the right edge of `other`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgBox, PgPoint};
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let tile = PgBox(PgPoint(0.0, 0.0), PgPoint(1.5, 1.0));
+    /// let found_drawing_ids = shapes
+    ///     .select(drawing_id)
+    ///     .filter(bounds.does_not_extend_right_of(tile))
+    ///     .load(&connection);
+    /// assert_eq!(Ok(vec![1]), found_drawing_ids);
+    /// # }
+    /// ```
+    fn does_not_extend_right_of<T>(self, other: T) -> DoesNotExtendRightOf<Self, T::Expression>
+    where
+        T: AsExpression<ST>,
+    {
+        DoesNotExtendRightOf::new(self, other.as_expression())
+    }
+
+    /// Creates a PostgreSQL `&>` expression.
+    ///
+    /// True if the left edge of this value is not left of the left edge of `other`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgBox, PgPoint};
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let tile = PgBox(PgPoint(-0.5, 0.0), PgPoint(3.0, 3.0));
+    /// let found_drawing_ids = shapes
+    ///     .select(drawing_id)
+    ///     .filter(bounds.does_not_extend_left_of(tile))
+    ///     .load(&connection);
+    /// assert_eq!(Ok(vec![2]), found_drawing_ids);
+    /// # }
+    /// ```
+    fn does_not_extend_left_of<T>(self, other: T) -> DoesNotExtendLeftOf<Self, T::Expression>
+    where
+        T: AsExpression<ST>,
+    {
+        DoesNotExtendLeftOf::new(self, other.as_expression())
+    }
+
+    /// Creates a PostgreSQL `&<|` expression.
+    ///
+    /// True if the top edge of this value is not above the top edge of `other`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgBox, PgPoint};
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let tile = PgBox(PgPoint(0.0, 0.0), PgPoint(1.0, 2.0));
+    /// let found_drawing_ids = shapes
+    ///     .select(drawing_id)
+    ///     .filter(bounds.does_not_extend_above(tile))
+    ///     .load(&connection);
+    /// assert_eq!(Ok(vec![1]), found_drawing_ids);
+    /// # }
+    /// ```
+    fn does_not_extend_above<T>(self, other: T) -> DoesNotExtendAbove<Self, T::Expression>
+    where
+        T: AsExpression<ST>,
+    {
+        DoesNotExtendAbove::new(self, other.as_expression())
+    }
+
+    /// Creates a PostgreSQL `|&>` expression.
+    ///
+    /// True if the bottom edge of this value is not below the bottom edge of `other`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgBox, PgPoint};
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let tile = PgBox(PgPoint(0.0, 0.0), PgPoint(1.0, 1.0));
+    /// let found_drawing_ids = shapes
+    ///     .select(drawing_id)
+    ///     .filter(bounds.does_not_extend_below(tile))
+    ///     .load(&connection);
+    /// assert_eq!(Ok(vec![2]), found_drawing_ids);
+    /// # }
+    /// ```
+    fn does_not_extend_below<T>(self, other: T) -> DoesNotExtendBelow<Self, T::Expression>
+    where
+        T: AsExpression<ST>,
+    {
+        DoesNotExtendBelow::new(self, other.as_expression())
+    }
+}
+
+impl<T: Expression<SqlType = sql_types::Box>> PgExtentExpressionMethods<sql_types::Box> for T {}
+impl<T: Expression<SqlType = Circle>> PgExtentExpressionMethods<Circle> for T {}
+impl<T: Expression<SqlType = Polygon>> PgExtentExpressionMethods<Polygon> for T {}
+
+/// Translation, scaling and rotation of points, boxes, circles and paths by a point.
+///
+/// The Rust `+`, `-`, `*` and `/` operators build the same expressions as these methods. Diesel
+/// implements them for bound values, the functions in `diesel_geometry::dsl` and the results of
+/// other operators, but only for columns of numeric types. The orphan rules prevent this crate
+/// from implementing them for columns, so invoke Diesel's `numeric_expr!` on a geometric column
+/// to use the operators with it:
+///
+/// ```rust
+/// # #![allow(dead_code)]
+/// # #[macro_use] extern crate diesel;
+/// # extern crate diesel_geometry;
+/// # include!("../../doctest_setup.rs");
+/// # use diesel_geometry::data_types::{PgBox, PgPoint};
+/// numeric_expr!(schema::shapes::bounds);
+///
+/// # fn main() {
+/// #     let connection = establish_connection();
+/// #     use schema::shapes::dsl::*;
+/// diesel::update(shapes)
+///     .set(bounds.eq(bounds + PgPoint(10.0, 0.0)))
+///     .execute(&connection)
+///     .unwrap();
+/// let moved_bounds = shapes
+///     .select(bounds)
+///     .filter(drawing_id.eq(1))
+///     .first(&connection);
+/// assert_eq!(Ok(PgBox(PgPoint(9.0, -1.0), PgPoint(11.0, 1.0))), moved_bounds);
+/// # }
+/// ```
+pub trait PgTransformExpressionMethods<ST>: Expression + Sized {
+    /// Creates a PostgreSQL `+` expression.
+    ///
+    /// Translates this value by the vector from the origin to `other`. The result has the same
+    /// SQL type as this value.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgBox, PgCircle, PgPoint};
+    /// # use diesel_geometry::sql_types;
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// #     use schema::shapes::dsl::*;
+    /// diesel::update(shapes)
+    ///     .set(bounds.eq(bounds.plus(PgPoint(10.0, 0.0))))
+    ///     .execute(&connection)
+    ///     .unwrap();
+    /// let moved_bounds = shapes
+    ///     .select(bounds)
+    ///     .filter(drawing_id.eq(1))
+    ///     .first(&connection);
+    /// assert_eq!(Ok(PgBox(PgPoint(9.0, -1.0), PgPoint(11.0, 1.0))), moved_bounds);
+    /// # }
+    /// ```
+    fn plus<T>(self, other: T) -> ops::Add<Self, T::Expression>
+    where
+        Self::SqlType: diesel_sql_types::ops::Add,
+        T: AsExpression<ST>,
+    {
+        ops::Add::new(self, other.as_expression())
+    }
+
+    /// Creates a PostgreSQL `-` expression.
+    ///
+    /// Translates this value by the vector from `other` to the origin. The result has the same
+    /// SQL type as this value.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgBox, PgCircle, PgPoint};
+    /// # use diesel_geometry::sql_types;
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// let moved = diesel::select(
+    ///     PgCircle(PgPoint(3.0, 4.0), 1.0)
+    ///         .into_sql::<sql_types::Circle>()
+    ///         .minus(PgPoint(3.0, 3.0)),
+    /// ).get_result(&connection);
+    /// assert_eq!(Ok(PgCircle(PgPoint(0.0, 1.0), 1.0)), moved);
+    /// # }
+    /// ```
+    fn minus<T>(self, other: T) -> ops::Sub<Self, T::Expression>
+    where
+        Self::SqlType: diesel_sql_types::ops::Sub,
+        T: AsExpression<ST>,
+    {
+        ops::Sub::new(self, other.as_expression())
+    }
+
+    /// Creates a PostgreSQL `*` expression.
+    ///
+    /// Scales and rotates this value around the origin, treating `other` as a complex number.
+    /// The result has the same SQL type as this value.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgBox, PgCircle, PgPoint};
+    /// # use diesel_geometry::sql_types;
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// // Multiplying by (0, 1) rotates by 90 degrees counter-clockwise.
+    /// let rotated = diesel::select(
+    ///     PgPoint(2.0, 0.0)
+    ///         .into_sql::<sql_types::Point>()
+    ///         .times(PgPoint(0.0, 1.0)),
+    /// ).get_result(&connection);
+    /// assert_eq!(Ok(PgPoint(0.0, 2.0)), rotated);
+    /// # }
+    /// ```
+    fn times<T>(self, other: T) -> ops::Mul<Self, T::Expression>
+    where
+        Self::SqlType: diesel_sql_types::ops::Mul,
+        T: AsExpression<ST>,
+    {
+        ops::Mul::new(self, other.as_expression())
+    }
+
+    /// Creates a PostgreSQL `/` expression.
+    ///
+    /// Scales and rotates this value around the origin, dividing by `other` treated as a complex
+    /// number. The result has the same SQL type as this value.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgBox, PgCircle, PgPoint};
+    /// # use diesel_geometry::sql_types;
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// let scaled = diesel::select(
+    ///     PgBox(PgPoint(0.0, 0.0), PgPoint(4.0, 2.0))
+    ///         .into_sql::<sql_types::Box>()
+    ///         .divided_by(PgPoint(2.0, 0.0)),
+    /// ).get_result(&connection);
+    /// assert_eq!(Ok(PgBox(PgPoint(0.0, 0.0), PgPoint(2.0, 1.0))), scaled);
+    /// # }
+    /// ```
+    fn divided_by<T>(self, other: T) -> ops::Div<Self, T::Expression>
+    where
+        Self::SqlType: diesel_sql_types::ops::Div,
+        T: AsExpression<ST>,
+    {
+        ops::Div::new(self, other.as_expression())
+    }
+}
+
+// Points, Boxes, Circles and Paths can be translated, scaled and rotated by a Point
+pub trait CanBeTransformed {}
+impl CanBeTransformed for Point {}
+impl CanBeTransformed for sql_types::Box {}
+impl CanBeTransformed for Circle {}
+impl CanBeTransformed for Path {}
+
+impl<T> PgTransformExpressionMethods<Point> for T
+where
+    T: Expression,
+    T::SqlType: CanBeTransformed,
+{}
+
+pub trait PgMeasurementExpressionMethods: Expression + Sized {
+    /// Creates a PostgreSQL `@-@` expression.
+    ///
+    /// The length of this line segment or path, as a `Double`. The length of a closed path
+    /// includes the segment joining its last point back to its first.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgLineSegment, PgPoint};
+    /// # use diesel_geometry::sql_types;
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let segment = PgLineSegment(PgPoint(0.0, 0.0), PgPoint(3.0, 4.0));
+    /// let segment_length = diesel::select(segment.into_sql::<sql_types::Lseg>().length())
+    ///     .get_result(&connection);
+    /// assert_eq!(Ok(5.0), segment_length);
+    /// # }
+    /// ```
+    fn length(self) -> Length<Self>
+    where
+        Self::SqlType: HasLength,
+    {
+        Length::new(self)
+    }
+
+    /// Creates a PostgreSQL `@@` expression.
+    ///
+    /// The center of this line segment, box, polygon or circle, as a `Point`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::PgPoint;
+    /// # use diesel_geometry::sql_types;
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let bounds_centers = shapes
+    ///     .select(bounds.center())
+    ///     .order(drawing_id)
+    ///     .load(&connection);
+    /// assert_eq!(Ok(vec![PgPoint(0.0, 0.0), PgPoint(1.0, 2.0)]), bounds_centers);
+    /// # }
+    /// ```
+    fn center(self) -> Center<Self>
+    where
+        Self::SqlType: HasCenter,
+    {
+        Center::new(self)
+    }
+
+    /// Creates a PostgreSQL `#` expression.
+    ///
+    /// The number of points in this path or polygon, as an `Integer`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgPoint, PgPolygon};
+    /// # use diesel_geometry::sql_types;
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let triangle = PgPolygon(vec![PgPoint(0.0, 0.0), PgPoint(1.0, 0.0), PgPoint(0.0, 1.0)]);
+    /// let corners = diesel::select(triangle.into_sql::<sql_types::Polygon>().num_points())
+    ///     .get_result(&connection);
+    /// assert_eq!(Ok(3), corners);
+    /// # }
+    /// ```
+    fn num_points(self) -> NumPoints<Self>
+    where
+        Self::SqlType: HasPoints,
+    {
+        NumPoints::new(self)
+    }
+}
+
+/// Implemented by every geometric SQL type.
+pub trait Geometric {}
+impl Geometric for Point {}
+impl Geometric for Lseg {}
+impl Geometric for Line {}
+impl Geometric for sql_types::Box {}
+impl Geometric for Path {}
+impl Geometric for Polygon {}
+impl Geometric for Circle {}
+
+/// Implemented by the geometric SQL types which Postgres can measure the length of with `@-@`.
+pub trait HasLength: Geometric {}
+impl HasLength for Lseg {}
+impl HasLength for Path {}
+
+/// Implemented by the geometric SQL types which Postgres can find the center of with `@@`.
+pub trait HasCenter: Geometric {}
+impl HasCenter for Lseg {}
+impl HasCenter for sql_types::Box {}
+impl HasCenter for Polygon {}
+impl HasCenter for Circle {}
+
+/// Implemented by the geometric SQL types which Postgres can count the points of with `#`.
+pub trait HasPoints: Geometric {}
+impl HasPoints for Path {}
+impl HasPoints for Polygon {}
+
+impl<T> PgMeasurementExpressionMethods for T
+where
+    T: Expression,
+    T::SqlType: Geometric,
+{}
+
+pub trait PgArrayIsContainedByExpressionMethods<ST>: Expression + Sized {
+    /// Creates a PostgreSQL `@> ANY(...)` expression, which is true if at least one element of
+    /// this array is contained by `other`.
+    ///
+    /// Accepts the same element types as
+    /// [`is_contained_by`](PgIsContainedByExpressionMethods::is_contained_by).
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgBox, PgPoint};
+    /// # use diesel_geometry::sql_types;
+    /// # use diesel::sql_types::Array;
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// let waypoints = vec![PgPoint(1.0, 1.0), PgPoint(8.0, 8.0)];
+    /// let viewport = PgBox(PgPoint(0.0, 0.0), PgPoint(2.0, 2.0));
+    /// let any_visible = diesel::select(
+    ///     waypoints
+    ///         .into_sql::<Array<sql_types::Point>>()
+    ///         .any_is_contained_by(viewport.into_sql::<sql_types::Box>()),
+    /// ).get_result(&connection);
+    /// assert_eq!(Ok(true), any_visible);
+    /// # }
+    /// ```
+    fn any_is_contained_by<T, E>(self, other: T) -> Contains<T::Expression, Any<Self>>
+    where
+        Self: Expression<SqlType = Array<E>>,
+        T: AsExpression<ST>,
+    {
+        Contains::new(other.as_expression(), any(self))
+    }
+
+    /// Creates a PostgreSQL `@> ALL(...)` expression, which is true if every element of this
+    /// array is contained by `other`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgCircle, PgPoint};
+    /// # use diesel_geometry::sql_types;
+    /// # use diesel::sql_types::Array;
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// let waypoints = vec![PgPoint(1.0, 1.0), PgPoint(8.0, 8.0)];
+    /// let range = PgCircle(PgPoint(0.0, 0.0), 5.0);
+    /// let all_in_range = diesel::select(
+    ///     waypoints
+    ///         .into_sql::<Array<sql_types::Point>>()
+    ///         .all_are_contained_by(range.into_sql::<sql_types::Circle>()),
+    /// ).get_result(&connection);
+    /// assert_eq!(Ok(false), all_in_range);
+    /// # }
+    /// ```
+    fn all_are_contained_by<T, E>(self, other: T) -> Contains<T::Expression, All<Self>>
+    where
+        Self: Expression<SqlType = Array<E>>,
+        T: AsExpression<ST>,
+    {
+        Contains::new(other.as_expression(), all(self))
+    }
+}
+
+impl<T, E> PgArrayIsContainedByExpressionMethods<Circle> for T
+where
+    T: Expression<SqlType = Array<E>>,
+    E: CanBeContainedByCircle,
+{}
+impl<T, E> PgArrayIsContainedByExpressionMethods<sql_types::Box> for T
+where
+    T: Expression<SqlType = Array<E>>,
+    E: CanBeContainedByBox,
+{}
+impl<T, E> PgArrayIsContainedByExpressionMethods<Polygon> for T
+where
+    T: Expression<SqlType = Array<E>>,
+    E: CanBeContainedByPolygon,
+{}
@@ -0,0 +1,699 @@
+use diesel::sql_types::{Bool, Double, Integer, Nullable};
+
+use super::expression_methods::{Geometric, HasLength, HasPoints};
+use sql_types::{self, Circle, Line, Lseg, Path, Point, Polygon};
+
+/// Implemented by the geometric SQL types accepted by the Postgres `area` function. `Area` is the
+/// SQL type of the result.
+pub trait HasArea: Geometric {
+    type Area;
+}
+
+impl HasArea for sql_types::Box {
+    type Area = Double;
+}
+
+impl HasArea for Circle {
+    type Area = Double;
+}
+
+// Postgres returns `NULL` for the area of an open path.
+impl HasArea for Path {
+    type Area = Nullable<Double>;
+}
+
+/// Implemented by the geometric SQL types accepted by the Postgres `center` function. Unlike the
+/// `@@` operator, the function does not accept line segments or polygons.
+pub trait CanBeCentered: Geometric {}
+impl CanBeCentered for sql_types::Box {}
+impl CanBeCentered for Circle {}
+
+/// Implemented by the geometric SQL types which the Postgres conversion function for `ST`
+/// accepts, such as `box(circle)` for `CanConvertTo<Box>`.
+pub trait CanConvertTo<ST>: Geometric {}
+impl CanConvertTo<sql_types::Box> for Point {}
+impl CanConvertTo<sql_types::Box> for Polygon {}
+impl CanConvertTo<sql_types::Box> for Circle {}
+impl CanConvertTo<Circle> for sql_types::Box {}
+impl CanConvertTo<Circle> for Polygon {}
+impl CanConvertTo<Point> for Lseg {}
+impl CanConvertTo<Point> for sql_types::Box {}
+impl CanConvertTo<Point> for Polygon {}
+impl CanConvertTo<Point> for Circle {}
+impl CanConvertTo<Polygon> for sql_types::Box {}
+impl CanConvertTo<Polygon> for Path {}
+impl CanConvertTo<Polygon> for Circle {}
+
+sql_function! {
+    /// Represents the Postgres `area` function, which is the area of a box, circle or closed path.
+    /// The area of an open path is `NULL`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::dsl::area;
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let areas = shapes.select(area(bounds)).load(&connection);
+    /// assert_eq!(Ok(vec![4.0, 4.0]), areas);
+    /// # }
+    /// ```
+    fn area<ST: HasArea>(x: ST) -> ST::Area;
+}
+
+sql_function! {
+    /// Represents the Postgres `center` function, which is the center of a box or circle.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::PgPoint;
+    /// # use diesel_geometry::dsl::center;
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let centers = shapes
+    ///     .select(center(bounds))
+    ///     .order(drawing_id)
+    ///     .load(&connection);
+    /// assert_eq!(Ok(vec![PgPoint(0.0, 0.0), PgPoint(1.0, 2.0)]), centers);
+    /// # }
+    /// ```
+    fn center<ST: CanBeCentered>(x: ST) -> Point;
+}
+
+sql_function! {
+    /// Represents the Postgres `diameter` function, which is the diameter of a circle.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgCircle, PgPoint};
+    /// # use diesel_geometry::dsl::diameter;
+    /// # use diesel_geometry::sql_types::Circle;
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// let circle = PgCircle(PgPoint(0.0, 0.0), 2.0).into_sql::<Circle>();
+    /// let size = diesel::select(diameter(circle)).get_result(&connection);
+    /// assert_eq!(Ok(4.0), size);
+    /// # }
+    /// ```
+    fn diameter(x: Circle) -> Double;
+}
+
+sql_function! {
+    /// Represents the Postgres `height` function, which is the vertical size of a box.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::dsl::height;
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let heights = shapes.select(height(bounds)).load(&connection);
+    /// assert_eq!(Ok(vec![2.0, 2.0]), heights);
+    /// # }
+    /// ```
+    fn height(x: sql_types::Box) -> Double;
+}
+
+sql_function! {
+    /// Represents the Postgres `width` function, which is the horizontal size of a box.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::dsl::width;
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let widths = shapes.select(width(bounds)).load(&connection);
+    /// assert_eq!(Ok(vec![2.0, 2.0]), widths);
+    /// # }
+    /// ```
+    fn width(x: sql_types::Box) -> Double;
+}
+
+sql_function! {
+    /// Represents the Postgres `radius` function, which is the radius of a circle.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgCircle, PgPoint};
+    /// # use diesel_geometry::dsl::radius;
+    /// # use diesel_geometry::sql_types::Circle;
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// let circle = PgCircle(PgPoint(0.0, 0.0), 2.0).into_sql::<Circle>();
+    /// let size = diesel::select(radius(circle)).get_result(&connection);
+    /// assert_eq!(Ok(2.0), size);
+    /// # }
+    /// ```
+    fn radius(x: Circle) -> Double;
+}
+
+sql_function! {
+    /// Represents the Postgres `length` function for geometric types, which is the length of a
+    /// line segment or path.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgLineSegment, PgPoint};
+    /// # use diesel_geometry::dsl::length;
+    /// # use diesel_geometry::sql_types::Lseg;
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// let segment = PgLineSegment(PgPoint(0.0, 0.0), PgPoint(3.0, 4.0)).into_sql::<Lseg>();
+    /// let segment_length = diesel::select(length(segment)).get_result(&connection);
+    /// assert_eq!(Ok(5.0), segment_length);
+    /// # }
+    /// ```
+    fn length<ST: HasLength>(x: ST) -> Double;
+}
+
+sql_function! {
+    /// Represents the Postgres `npoints` function, which is the number of points in a path or
+    /// polygon.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgPoint, PgPolygon};
+    /// # use diesel_geometry::dsl::npoints;
+    /// # use diesel_geometry::sql_types::Polygon;
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// let triangle = PgPolygon(vec![PgPoint(0.0, 0.0), PgPoint(1.0, 0.0), PgPoint(0.0, 1.0)]);
+    /// let corners = diesel::select(npoints(triangle.into_sql::<Polygon>()))
+    ///     .get_result(&connection);
+    /// assert_eq!(Ok(3), corners);
+    /// # }
+    /// ```
+    fn npoints<ST: HasPoints>(x: ST) -> Integer;
+}
+
+sql_function! {
+    /// Represents the Postgres `box` function for a single value, which is the bounding box of a
+    /// circle or polygon, or an empty box at a point.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgBox, PgPoint};
+    /// # use diesel_geometry::dsl::to_box;
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let point_boxes = shapes
+    ///     .select(to_box(centroid))
+    ///     .order(drawing_id)
+    ///     .load(&connection);
+    /// assert_eq!(
+    ///     Ok(vec![
+    ///         PgBox(PgPoint(0.0, 0.0), PgPoint(0.0, 0.0)),
+    ///         PgBox(PgPoint(1.0, 2.0), PgPoint(1.0, 2.0)),
+    ///     ]),
+    ///     point_boxes
+    /// );
+    /// # }
+    /// ```
+    #[sql_name = "box"]
+    fn to_box<ST: CanConvertTo<sql_types::Box>>(x: ST) -> sql_types::Box;
+}
+
+sql_function! {
+    /// Represents the two argument form of the Postgres `box` function, which is the box with
+    /// opposite corners `a` and `b`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgBox, PgPoint};
+    /// # use diesel_geometry::dsl::box_from_points;
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let spanned = shapes
+    ///     .select(box_from_points(centroid, PgPoint(4.0, 4.0)))
+    ///     .filter(drawing_id.eq(2))
+    ///     .first(&connection);
+    /// assert_eq!(Ok(PgBox(PgPoint(1.0, 2.0), PgPoint(4.0, 4.0))), spanned);
+    /// # }
+    /// ```
+    #[sql_name = "box"]
+    fn box_from_points(a: Point, b: Point) -> sql_types::Box;
+}
+
+sql_function! {
+    /// Represents the Postgres `circle` function for a single value, which is the circle around a
+    /// box, or the circle centered on a polygon's points with their mean distance as its radius.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgCircle, PgPoint};
+    /// # use diesel_geometry::dsl::to_circle;
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let circles = shapes
+    ///     .select(to_circle(bounds))
+    ///     .order(drawing_id)
+    ///     .load(&connection);
+    /// let radius = 2.0f64.sqrt();
+    /// assert_eq!(
+    ///     Ok(vec![
+    ///         PgCircle(PgPoint(0.0, 0.0), radius),
+    ///         PgCircle(PgPoint(1.0, 2.0), radius),
+    ///     ]),
+    ///     circles
+    /// );
+    /// # }
+    /// ```
+    #[sql_name = "circle"]
+    fn to_circle<ST: CanConvertTo<Circle>>(x: ST) -> Circle;
+}
+
+sql_function! {
+    /// Represents the two argument form of the Postgres `circle` function, which is the circle
+    /// with the given center and radius.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgCircle, PgPoint};
+    /// # use diesel_geometry::dsl::circle_from_center;
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let reach = shapes
+    ///     .select(circle_from_center(centroid, 2.5))
+    ///     .filter(drawing_id.eq(2))
+    ///     .first(&connection);
+    /// assert_eq!(Ok(PgCircle(PgPoint(1.0, 2.0), 2.5)), reach);
+    /// # }
+    /// ```
+    #[sql_name = "circle"]
+    fn circle_from_center(center: Point, radius: Double) -> Circle;
+}
+
+sql_function! {
+    /// Represents the Postgres `point` function for a single value, which is the center of a line
+    /// segment, box, polygon or circle.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::PgPoint;
+    /// # use diesel_geometry::dsl::to_point;
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let centers = shapes
+    ///     .select(to_point(bounds))
+    ///     .order(drawing_id)
+    ///     .load(&connection);
+    /// assert_eq!(Ok(vec![PgPoint(0.0, 0.0), PgPoint(1.0, 2.0)]), centers);
+    /// # }
+    /// ```
+    #[sql_name = "point"]
+    fn to_point<ST: CanConvertTo<Point>>(x: ST) -> Point;
+}
+
+sql_function! {
+    /// Represents the two argument form of the Postgres `point` function, which is the point with
+    /// the given coordinates.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::PgPoint;
+    /// # use diesel_geometry::dsl::point_from_coordinates;
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// let point = diesel::select(point_from_coordinates(1.5, 2.0)).get_result(&connection);
+    /// assert_eq!(Ok(PgPoint(1.5, 2.0)), point);
+    /// # }
+    /// ```
+    #[sql_name = "point"]
+    fn point_from_coordinates(x: Double, y: Double) -> Point;
+}
+
+sql_function! {
+    /// Represents the Postgres `lseg` function for a box, which is the box's diagonal.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgLineSegment, PgPoint};
+    /// # use diesel_geometry::dsl::to_lseg;
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let diagonal = shapes
+    ///     .select(to_lseg(bounds))
+    ///     .filter(drawing_id.eq(2))
+    ///     .first(&connection);
+    /// assert_eq!(Ok(PgLineSegment(PgPoint(2.0, 3.0), PgPoint(0.0, 1.0))), diagonal);
+    /// # }
+    /// ```
+    #[sql_name = "lseg"]
+    fn to_lseg(x: sql_types::Box) -> Lseg;
+}
+
+sql_function! {
+    /// Represents the two argument form of the Postgres `lseg` function, which is the line segment
+    /// from `a` to `b`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgLineSegment, PgPoint};
+    /// # use diesel_geometry::dsl::lseg_from_points;
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let segment = shapes
+    ///     .select(lseg_from_points(centroid, PgPoint(4.0, 4.0)))
+    ///     .filter(drawing_id.eq(2))
+    ///     .first(&connection);
+    /// assert_eq!(Ok(PgLineSegment(PgPoint(1.0, 2.0), PgPoint(4.0, 4.0))), segment);
+    /// # }
+    /// ```
+    #[sql_name = "lseg"]
+    fn lseg_from_points(a: Point, b: Point) -> Lseg;
+}
+
+sql_function! {
+    /// Represents the Postgres `line` function, which is the line through `a` and `b`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgLine, PgPoint};
+    /// # use diesel_geometry::dsl::line_from_points;
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// let through = diesel::select(line_from_points(PgPoint(0.0, 1.0), PgPoint(1.0, 2.0)))
+    ///     .get_result(&connection);
+    /// assert_eq!(Ok(PgLine::from_slope_intercept(1.0, 1.0)), through);
+    /// # }
+    /// ```
+    #[sql_name = "line"]
+    fn line_from_points(a: Point, b: Point) -> Line;
+}
+
+sql_function! {
+    /// Represents the Postgres `polygon` function for a single value. A box becomes a polygon with
+    /// 4 points, and a circle a polygon with 12 points. Postgres raises an error for an open path.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgPoint, PgPolygon};
+    /// # use diesel_geometry::dsl::to_polygon;
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let outline = shapes
+    ///     .select(to_polygon(bounds))
+    ///     .filter(drawing_id.eq(2))
+    ///     .first(&connection);
+    /// let corners = vec![
+    ///     PgPoint(0.0, 1.0),
+    ///     PgPoint(0.0, 3.0),
+    ///     PgPoint(2.0, 3.0),
+    ///     PgPoint(2.0, 1.0),
+    /// ];
+    /// assert_eq!(Ok(PgPolygon(corners)), outline);
+    /// # }
+    /// ```
+    #[sql_name = "polygon"]
+    fn to_polygon<ST: CanConvertTo<Polygon>>(x: ST) -> Polygon;
+}
+
+sql_function! {
+    /// Represents the two argument form of the Postgres `polygon` function, which approximates a
+    /// circle with a polygon of `npts` points.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgCircle, PgPoint, PgPolygon};
+    /// # use diesel_geometry::dsl::polygon_from_circle;
+    /// # use diesel_geometry::sql_types::Circle;
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// let circle = PgCircle(PgPoint(0.0, 0.0), 1.0).into_sql::<Circle>();
+    /// let square = diesel::select(polygon_from_circle(4, circle))
+    ///     .get_result::<PgPolygon>(&connection);
+    /// assert_eq!(Ok(4), square.map(|polygon| polygon.0.len()));
+    /// # }
+    /// ```
+    #[sql_name = "polygon"]
+    fn polygon_from_circle(npts: Integer, circle: Circle) -> Polygon;
+}
+
+sql_function! {
+    /// Represents the Postgres `path` function, which is the closed path through a polygon's
+    /// points.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgPath, PgPoint, PgPolygon};
+    /// # use diesel_geometry::dsl::to_path;
+    /// # use diesel_geometry::sql_types::Polygon;
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// let corners = vec![PgPoint(0.0, 0.0), PgPoint(1.0, 0.0), PgPoint(0.0, 1.0)];
+    /// let triangle = PgPolygon(corners.clone()).into_sql::<Polygon>();
+    /// let outline = diesel::select(to_path(triangle)).get_result(&connection);
+    /// assert_eq!(
+    ///     Ok(PgPath {
+    ///         closed: true,
+    ///         points: corners,
+    ///     }),
+    ///     outline
+    /// );
+    /// # }
+    /// ```
+    #[sql_name = "path"]
+    fn to_path(x: Polygon) -> Path;
+}
+
+sql_function! {
+    /// Represents the Postgres `isclosed` function, which is true for a closed path.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgPath, PgPoint};
+    /// # use diesel_geometry::dsl::isclosed;
+    /// # use diesel_geometry::sql_types::Path;
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// let trace = PgPath {
+    ///     closed: false,
+    ///     points: vec![PgPoint(0.0, 0.0), PgPoint(1.0, 1.0)],
+    /// };
+    /// let closed = diesel::select(isclosed(trace.into_sql::<Path>())).get_result(&connection);
+    /// assert_eq!(Ok(false), closed);
+    /// # }
+    /// ```
+    fn isclosed(x: Path) -> Bool;
+}
+
+sql_function! {
+    /// Represents the Postgres `isopen` function, which is true for an open path.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgPath, PgPoint};
+    /// # use diesel_geometry::dsl::isopen;
+    /// # use diesel_geometry::sql_types::Path;
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// let trace = PgPath {
+    ///     closed: false,
+    ///     points: vec![PgPoint(0.0, 0.0), PgPoint(1.0, 1.0)],
+    /// };
+    /// let open = diesel::select(isopen(trace.into_sql::<Path>())).get_result(&connection);
+    /// assert_eq!(Ok(true), open);
+    /// # }
+    /// ```
+    fn isopen(x: Path) -> Bool;
+}
+
+sql_function! {
+    /// Represents the Postgres `pclose` function, which is the same path marked as closed.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgPath, PgPoint};
+    /// # use diesel_geometry::dsl::pclose;
+    /// # use diesel_geometry::sql_types::Path;
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// let points = vec![PgPoint(0.0, 0.0), PgPoint(1.0, 1.0)];
+    /// let trace = PgPath {
+    ///     closed: false,
+    ///     points: points.clone(),
+    /// };
+    /// let closed_trace = diesel::select(pclose(trace.into_sql::<Path>())).get_result(&connection);
+    /// assert_eq!(Ok(PgPath { closed: true, points }), closed_trace);
+    /// # }
+    /// ```
+    fn pclose(x: Path) -> Path;
+}
+
+sql_function! {
+    /// Represents the Postgres `popen` function, which is the same path marked as open.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgPath, PgPoint};
+    /// # use diesel_geometry::dsl::popen;
+    /// # use diesel_geometry::sql_types::Path;
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// let points = vec![PgPoint(0.0, 0.0), PgPoint(1.0, 1.0)];
+    /// let outline = PgPath {
+    ///     closed: true,
+    ///     points: points.clone(),
+    /// };
+    /// let trace = diesel::select(popen(outline.into_sql::<Path>())).get_result(&connection);
+    /// assert_eq!(Ok(PgPath { closed: false, points }), trace);
+    /// # }
+    /// ```
+    fn popen(x: Path) -> Path;
+}
+
+sql_function! {
+    /// Represents the Postgres `bound_box` function, which is the smallest box containing both
+    /// boxes.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::{PgBox, PgPoint};
+    /// # use diesel_geometry::dsl::bound_box;
+    /// #
+    /// # fn main() {
+    /// #     use schema::shapes::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let extent = shapes
+    ///     .select(bound_box(bounds, PgBox(PgPoint(4.0, 4.0), PgPoint(3.0, 3.0))))
+    ///     .filter(drawing_id.eq(1))
+    ///     .first(&connection);
+    /// assert_eq!(Ok(PgBox(PgPoint(-1.0, -1.0), PgPoint(4.0, 4.0))), extent);
+    /// # }
+    /// ```
+    fn bound_box(a: sql_types::Box, b: sql_types::Box) -> sql_types::Box;
+}
@@ -0,0 +1,81 @@
+//! The types returned by the functions in [`dsl`](super::dsl).
+
+use diesel::expression::Expression;
+
+use super::functions;
+
+/// The return type of `area(expr)`
+pub type Area<Expr> = functions::area::HelperType<<Expr as Expression>::SqlType, Expr>;
+
+/// The return type of `center(expr)`
+pub type Center<Expr> = functions::center::HelperType<<Expr as Expression>::SqlType, Expr>;
+
+/// The return type of `diameter(expr)`
+pub type Diameter<Expr> = functions::diameter::HelperType<Expr>;
+
+/// The return type of `height(expr)`
+pub type Height<Expr> = functions::height::HelperType<Expr>;
+
+/// The return type of `width(expr)`
+pub type Width<Expr> = functions::width::HelperType<Expr>;
+
+/// The return type of `radius(expr)`
+pub type Radius<Expr> = functions::radius::HelperType<Expr>;
+
+/// The return type of `length(expr)`
+pub type Length<Expr> = functions::length::HelperType<<Expr as Expression>::SqlType, Expr>;
+
+/// The return type of `npoints(expr)`
+pub type Npoints<Expr> = functions::npoints::HelperType<<Expr as Expression>::SqlType, Expr>;
+
+/// The return type of `to_box(expr)`
+pub type ToBox<Expr> = functions::to_box::HelperType<<Expr as Expression>::SqlType, Expr>;
+
+/// The return type of `to_circle(expr)`
+pub type ToCircle<Expr> = functions::to_circle::HelperType<<Expr as Expression>::SqlType, Expr>;
+
+/// The return type of `to_point(expr)`
+pub type ToPoint<Expr> = functions::to_point::HelperType<<Expr as Expression>::SqlType, Expr>;
+
+/// The return type of `to_polygon(expr)`
+pub type ToPolygon<Expr> = functions::to_polygon::HelperType<<Expr as Expression>::SqlType, Expr>;
+
+/// The return type of `box_from_points(a, b)`
+pub type BoxFromPoints<A, B> = functions::box_from_points::HelperType<A, B>;
+
+/// The return type of `circle_from_center(center, radius)`
+pub type CircleFromCenter<Center, Radius> =
+    functions::circle_from_center::HelperType<Center, Radius>;
+
+/// The return type of `point_from_coordinates(x, y)`
+pub type PointFromCoordinates<X, Y> = functions::point_from_coordinates::HelperType<X, Y>;
+
+/// The return type of `to_lseg(expr)`
+pub type ToLseg<Expr> = functions::to_lseg::HelperType<Expr>;
+
+/// The return type of `lseg_from_points(a, b)`
+pub type LsegFromPoints<A, B> = functions::lseg_from_points::HelperType<A, B>;
+
+/// The return type of `line_from_points(a, b)`
+pub type LineFromPoints<A, B> = functions::line_from_points::HelperType<A, B>;
+
+/// The return type of `polygon_from_circle(npts, expr)`
+pub type PolygonFromCircle<Npts, Expr> = functions::polygon_from_circle::HelperType<Npts, Expr>;
+
+/// The return type of `to_path(expr)`
+pub type ToPath<Expr> = functions::to_path::HelperType<Expr>;
+
+/// The return type of `isclosed(expr)`
+pub type Isclosed<Expr> = functions::isclosed::HelperType<Expr>;
+
+/// The return type of `isopen(expr)`
+pub type Isopen<Expr> = functions::isopen::HelperType<Expr>;
+
+/// The return type of `pclose(expr)`
+pub type Pclose<Expr> = functions::pclose::HelperType<Expr>;
+
+/// The return type of `popen(expr)`
+pub type Popen<Expr> = functions::popen::HelperType<Expr>;
+
+/// The return type of `bound_box(a, b)`
+pub type BoundBox<A, B> = functions::bound_box::HelperType<A, B>;
@@ -5,6 +5,8 @@
 //! kept separate purely for documentation purposes.
 
 pub(crate) mod expression_methods;
+pub(crate) mod functions;
+pub mod helper_types;
 #[doc(hidden)]
 pub mod operators;
 
@@ -13,4 +15,9 @@ pub mod operators;
 /// This module will be glob imported by
 /// [`diesel_geometry::dsl`](::diesel_geometry::dsl) when compiled with the `feature =
 /// "postgres"` flag.
-pub mod dsl {}
+pub mod dsl {
+    #[doc(inline)]
+    pub use super::functions::*;
+    #[doc(inline)]
+    pub use super::helper_types::*;
+}
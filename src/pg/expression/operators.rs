@@ -1,3 +1,86 @@
+use diesel::expression::{Expression, NonAggregate};
 use diesel::pg::Pg;
+use diesel::query_builder::{AstPass, QueryFragment};
+use diesel::result::QueryResult;
+use diesel::sql_types::{Double, Integer};
+
+use sql_types::Point;
+
+use super::expression_methods::{HasClosestPointTo, HasIntersectionWith};
 
 diesel_infix_operator!(SameAs, " ~= ", backend: Pg);
+diesel_infix_operator!(Distance, " <-> ", Double, backend: Pg);
+diesel_infix_operator!(IsStrictlyLeftOf, " << ", backend: Pg);
+diesel_infix_operator!(IsStrictlyRightOf, " >> ", backend: Pg);
+diesel_infix_operator!(IsStrictlyBelow, " <<| ", backend: Pg);
+diesel_infix_operator!(IsStrictlyAbove, " |>> ", backend: Pg);
+diesel_infix_operator!(IsBelow, " <^ ", backend: Pg);
+diesel_infix_operator!(IsAbove, " >^ ", backend: Pg);
+diesel_infix_operator!(DoesNotExtendRightOf, " &< ", backend: Pg);
+diesel_infix_operator!(DoesNotExtendLeftOf, " &> ", backend: Pg);
+diesel_infix_operator!(DoesNotExtendAbove, " &<| ", backend: Pg);
+diesel_infix_operator!(DoesNotExtendBelow, " |&> ", backend: Pg);
+diesel_infix_operator!(Intersects, " ?# ", backend: Pg);
+diesel_infix_operator!(IsParallelTo, " ?|| ", backend: Pg);
+diesel_infix_operator!(IsPerpendicularTo, " ?-| ", backend: Pg);
+diesel_infix_operator!(IsHorizontalWith, " ?- ", backend: Pg);
+diesel_infix_operator!(IsVerticalWith, " ?| ", backend: Pg);
+diesel_prefix_operator!(IsHorizontal, "?- ", backend: Pg);
+diesel_prefix_operator!(IsVertical, "?| ", backend: Pg);
+diesel_prefix_operator!(Length, "@-@ ", Double, backend: Pg);
+diesel_prefix_operator!(Center, "@@ ", Point, backend: Pg);
+diesel_prefix_operator!(NumPoints, "# ", Integer, backend: Pg);
+
+// Like `diesel_infix_operator!`, but the SQL type of the result is looked up from the operand
+// types through `$output_trait`, for operators such as `#` which return a point for two segments
+// but a box for two boxes.
+macro_rules! infix_operator_with_output {
+    ($name:ident, $operator:expr, $output_trait:ident) => {
+        #[derive(Debug, Clone, Copy, QueryId, DieselNumericOps)]
+        #[doc(hidden)]
+        pub struct $name<T, U> {
+            pub(crate) left: T,
+            pub(crate) right: U,
+        }
+
+        impl<T, U> $name<T, U> {
+            pub fn new(left: T, right: U) -> Self {
+                $name { left, right }
+            }
+        }
+
+        impl_selectable_expression!($name<T, U>);
+
+        impl<T, U> Expression for $name<T, U>
+        where
+            T: Expression,
+            U: Expression,
+            T::SqlType: $output_trait<U::SqlType>,
+        {
+            type SqlType = <T::SqlType as $output_trait<U::SqlType>>::Output;
+        }
+
+        impl<T, U> NonAggregate for $name<T, U>
+        where
+            T: NonAggregate,
+            U: NonAggregate,
+        {
+        }
+
+        impl<T, U> QueryFragment<Pg> for $name<T, U>
+        where
+            T: QueryFragment<Pg>,
+            U: QueryFragment<Pg>,
+        {
+            fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+                self.left.walk_ast(out.reborrow())?;
+                out.push_sql($operator);
+                self.right.walk_ast(out.reborrow())?;
+                Ok(())
+            }
+        }
+    };
+}
+
+infix_operator_with_output!(Intersection, " # ", HasIntersectionWith);
+infix_operator_with_output!(ClosestPoint, " ## ", HasClosestPointTo);
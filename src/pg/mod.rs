@@ -13,5 +13,12 @@ pub mod types;
 /// level types.
 pub mod data_types {
     #[doc(inline)]
-    pub use super::types::geometric::{PgBox, PgCircle, PgPoint};
+    pub use super::types::geometric::{
+        ApproxEq, GeometryDecodeError, PgBox, PgCircle, PgLine, PgLineSegment, PgPath, PgPoint,
+        PgPolygon,
+    };
+    #[doc(inline)]
+    pub use super::types::text::GeometryParseError;
+    #[doc(inline)]
+    pub use super::types::validation::{GeometryValidationError, Validate};
 }
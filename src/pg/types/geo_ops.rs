@@ -0,0 +1,833 @@
+//! Client side versions of the Postgres geometric operators and functions, ported from geo_ops.c
+//! so they give the same answers as the database.
+//!
+//! https://github.com/postgres/postgres/blob/REL_15_STABLE/src/backend/utils/adt/geo_ops.c
+
+use super::geometric::{PgBox, PgCircle, PgLine, PgLineSegment, PgPoint};
+
+/// Postgres compares geometric coordinates with a tolerance of `EPSILON`, see `FPeq` in
+/// geo_decls.h.
+const EPSILON: f64 = 1.0E-06;
+
+pub(super) fn fp_zero(a: f64) -> bool {
+    a.abs() <= EPSILON
+}
+
+pub(super) fn fp_eq(a: f64, b: f64) -> bool {
+    a == b || (a - b).abs() <= EPSILON
+}
+
+fn fp_lt(a: f64, b: f64) -> bool {
+    a + EPSILON < b
+}
+
+fn fp_le(a: f64, b: f64) -> bool {
+    a <= b + EPSILON
+}
+
+fn fp_gt(a: f64, b: f64) -> bool {
+    a > b + EPSILON
+}
+
+fn fp_ge(a: f64, b: f64) -> bool {
+    a + EPSILON >= b
+}
+
+// Postgres considers NaN equal to itself and greater than any other value, see float.h.
+pub(super) fn float8_eq(a: f64, b: f64) -> bool {
+    if a.is_nan() || b.is_nan() {
+        a.is_nan() && b.is_nan()
+    } else {
+        a == b
+    }
+}
+
+pub(super) fn float8_lt(a: f64, b: f64) -> bool {
+    !a.is_nan() && (b.is_nan() || a < b)
+}
+
+pub(super) fn float8_gt(a: f64, b: f64) -> bool {
+    !b.is_nan() && (a.is_nan() || a > b)
+}
+
+// Port of `pg_hypot` from float.c, which rounds differently than `f64::hypot`.
+fn pg_hypot(x: f64, y: f64) -> f64 {
+    if x.is_infinite() || y.is_infinite() {
+        return f64::INFINITY;
+    }
+    if x.is_nan() || y.is_nan() {
+        return f64::NAN;
+    }
+    let (x, y) = if x.abs() < y.abs() {
+        (y.abs(), x.abs())
+    } else {
+        (x.abs(), y.abs())
+    };
+    if y == 0.0 {
+        return x;
+    }
+    let yx = y / x;
+    x * (1.0 + yx * yx).sqrt()
+}
+
+// Port of `point_dt`.
+fn point_dt(p1: PgPoint, p2: PgPoint) -> f64 {
+    pg_hypot(p1.0 - p2.0, p1.1 - p2.1)
+}
+
+// Port of `point_sl`, the slope of the line through both points.
+pub(super) fn point_sl(p1: PgPoint, p2: PgPoint) -> f64 {
+    if fp_eq(p1.0, p2.0) {
+        f64::INFINITY
+    } else if fp_eq(p1.1, p2.1) {
+        0.0
+    } else {
+        (p1.1 - p2.1) / (p1.0 - p2.0)
+    }
+}
+
+// Port of `point_invsl`, the slope of a line perpendicular to the one through both points.
+fn point_invsl(p1: PgPoint, p2: PgPoint) -> f64 {
+    if fp_eq(p1.0, p2.0) {
+        0.0
+    } else if fp_eq(p1.1, p2.1) {
+        f64::INFINITY
+    } else {
+        (p1.0 - p2.0) / (p2.1 - p1.1)
+    }
+}
+
+// Port of `line_invsl`.
+fn line_invsl(line: PgLine) -> f64 {
+    if fp_zero(line.a) {
+        f64::INFINITY
+    } else if fp_zero(line.b) {
+        0.0
+    } else {
+        line.b / line.a
+    }
+}
+
+// Port of `line_interpt_line`.
+fn line_interpt_line(l1: PgLine, l2: PgLine) -> Option<PgPoint> {
+    let (x, y) = if !fp_zero(l1.b) {
+        if fp_eq(l2.a, l1.a * (l2.b / l1.b)) {
+            return None;
+        }
+        let x = (l1.b * l2.c - l2.b * l1.c) / (l1.a * l2.b - l2.a * l1.b);
+        (x, -(l1.a * x + l1.c) / l1.b)
+    } else if !fp_zero(l2.b) {
+        if fp_eq(l1.a, l2.a * (l1.b / l2.b)) {
+            return None;
+        }
+        let x = (l2.b * l1.c - l1.b * l2.c) / (l2.a * l1.b - l1.a * l2.b);
+        (x, -(l2.a * x + l2.c) / l2.b)
+    } else {
+        return None;
+    };
+    // Avoid producing -0, the same as Postgres.
+    let x = if x == 0.0 { 0.0 } else { x };
+    let y = if y == 0.0 { 0.0 } else { y };
+    Some(PgPoint(x, y))
+}
+
+// Port of `line_closept_point`, which returns NaN if no perpendicular can be dropped.
+fn line_closept_point(line: PgLine, point: PgPoint) -> f64 {
+    let perpendicular = PgLine::from_point_slope(point, line_invsl(line));
+    match line_interpt_line(perpendicular, line) {
+        Some(closest) => point_dt(closest, point),
+        None => f64::NAN,
+    }
+}
+
+// Port of `lseg_contain_point`.
+fn lseg_contain_point(lseg: PgLineSegment, point: PgPoint) -> bool {
+    fp_eq(
+        point_dt(point, lseg.0) + point_dt(point, lseg.1),
+        point_dt(lseg.0, lseg.1),
+    )
+}
+
+// Port of `lseg_interpt_line`.
+fn lseg_interpt_line(lseg: PgLineSegment, line: PgLine) -> Option<PgPoint> {
+    let lseg_line = PgLine::from_point_slope(lseg.0, point_sl(lseg.0, lseg.1));
+    let interpt = line_interpt_line(lseg_line, line)?;
+    if !lseg_contain_point(lseg, interpt) {
+        return None;
+    }
+    // Snap to an end point to avoid rounding residue.
+    if point_eq_point(lseg.0, interpt) {
+        Some(lseg.0)
+    } else if point_eq_point(lseg.1, interpt) {
+        Some(lseg.1)
+    } else {
+        Some(interpt)
+    }
+}
+
+// Port of `lseg_closept_line`.
+fn lseg_closept_line(lseg: PgLineSegment, line: PgLine) -> PgPoint {
+    if let Some(interpt) = lseg_interpt_line(lseg, line) {
+        return interpt;
+    }
+    if line_closept_point(line, lseg.0) < line_closept_point(line, lseg.1) {
+        lseg.0
+    } else {
+        lseg.1
+    }
+}
+
+// Port of `lseg_closept_point`.
+fn lseg_closept_point(lseg: PgLineSegment, point: PgPoint) -> f64 {
+    let perpendicular = PgLine::from_point_slope(point, point_invsl(lseg.0, lseg.1));
+    point_dt(lseg_closept_line(lseg, perpendicular), point)
+}
+
+// Port of `point_eq_point`: if any coordinate is NaN, the points must be exactly equal.
+pub(super) fn point_eq_point(p1: PgPoint, p2: PgPoint) -> bool {
+    if p1.0.is_nan() || p1.1.is_nan() || p2.0.is_nan() || p2.1.is_nan() {
+        float8_eq(p1.0, p2.0) && float8_eq(p1.1, p2.1)
+    } else {
+        fp_eq(p1.0, p2.0) && fp_eq(p1.1, p2.1)
+    }
+}
+
+impl PgPoint {
+    /// The Euclidean distance between the points, the same as the Postgres `<->` operator.
+    pub fn distance(&self, other: &PgPoint) -> f64 {
+        point_dt(*self, *other)
+    }
+
+    /// The distance from this point to the nearest edge of `other`, or 0 if the box contains the
+    /// point. The same as the Postgres `<->` operator.
+    pub fn distance_to_box(&self, other: &PgBox) -> f64 {
+        other.distance_to_point(self)
+    }
+
+    /// The distance from this point to the edge of `other`, or 0 if the circle contains the
+    /// point. The same as the Postgres `<->` operator.
+    pub fn distance_to_circle(&self, other: &PgCircle) -> f64 {
+        other.distance_to_point(self)
+    }
+
+    /// True if `other` contains this point, the same as the Postgres `<@` operator. Points on the
+    /// edge of the box are contained, without any tolerance.
+    pub fn is_contained_by_box(&self, other: &PgBox) -> bool {
+        other.contains_point(self)
+    }
+
+    /// True if `other` contains this point, the same as the Postgres `<@` operator. Points on the
+    /// edge of the circle are contained, without any tolerance.
+    pub fn is_contained_by_circle(&self, other: &PgCircle) -> bool {
+        other.contains_point(self)
+    }
+
+    /// The same as the Postgres `<<` operator.
+    pub fn is_strictly_left_of(&self, other: &PgPoint) -> bool {
+        fp_lt(self.0, other.0)
+    }
+
+    /// The same as the Postgres `>>` operator.
+    pub fn is_strictly_right_of(&self, other: &PgPoint) -> bool {
+        fp_gt(self.0, other.0)
+    }
+
+    /// The same as the Postgres `<<|` operator. For points this is identical to
+    /// [`is_below`](PgPoint::is_below).
+    pub fn is_strictly_below(&self, other: &PgPoint) -> bool {
+        fp_lt(self.1, other.1)
+    }
+
+    /// The same as the Postgres `|>>` operator. For points this is identical to
+    /// [`is_above`](PgPoint::is_above).
+    pub fn is_strictly_above(&self, other: &PgPoint) -> bool {
+        fp_gt(self.1, other.1)
+    }
+
+    /// Always 0, the same as the Postgres `area(box(point))`.
+    pub fn area(&self) -> f64 {
+        0.0
+    }
+
+    /// Always 0. Postgres has no function for this.
+    pub fn perimeter(&self) -> f64 {
+        0.0
+    }
+
+    /// This point itself, the same as the Postgres `center(box(point))`.
+    pub fn center(&self) -> PgPoint {
+        *self
+    }
+
+    /// Always 0, the same as the Postgres `width(box(point))`.
+    pub fn width(&self) -> f64 {
+        0.0
+    }
+
+    /// Always 0, the same as the Postgres `height(box(point))`.
+    pub fn height(&self) -> f64 {
+        0.0
+    }
+
+    /// The empty box at this point, the same as the Postgres `box(point)` function.
+    pub fn bounding_box(&self) -> PgBox {
+        PgBox(*self, *self)
+    }
+
+    /// The same as the Postgres `<^` operator.
+    pub fn is_below(&self, other: &PgPoint) -> bool {
+        self.is_strictly_below(other)
+    }
+
+    /// The same as the Postgres `>^` operator.
+    pub fn is_above(&self, other: &PgPoint) -> bool {
+        self.is_strictly_above(other)
+    }
+}
+
+impl PgBox {
+    /// True if this box contains `other`, the same as the Postgres `@>` operator.
+    pub fn contains(&self, other: &PgBox) -> bool {
+        let ((outer_low, outer_high), (inner_low, inner_high)) = (self.corners(), other.corners());
+        fp_ge(outer_high.0, inner_high.0)
+            && fp_le(outer_low.0, inner_low.0)
+            && fp_ge(outer_high.1, inner_high.1)
+            && fp_le(outer_low.1, inner_low.1)
+    }
+
+    /// True if this box contains `other`, the same as the Postgres `@>` operator. Points on the
+    /// edge of the box are contained, without any tolerance.
+    pub fn contains_point(&self, other: &PgPoint) -> bool {
+        let (low, high) = self.corners();
+        high.0 >= other.0 && low.0 <= other.0 && high.1 >= other.1 && low.1 <= other.1
+    }
+
+    /// True if `other` contains this box, the same as the Postgres `<@` operator.
+    pub fn is_contained_by(&self, other: &PgBox) -> bool {
+        other.contains(self)
+    }
+
+    /// True if the boxes overlap, the same as the Postgres `&&` operator.
+    pub fn overlaps_with(&self, other: &PgBox) -> bool {
+        let ((low1, high1), (low2, high2)) = (self.corners(), other.corners());
+        fp_le(low1.0, high2.0)
+            && fp_le(low2.0, high1.0)
+            && fp_le(low1.1, high2.1)
+            && fp_le(low2.1, high1.1)
+    }
+
+    /// The distance between the centers of the boxes, the same as the Postgres `<->` operator.
+    pub fn distance(&self, other: &PgBox) -> f64 {
+        point_dt(self.center(), other.center())
+    }
+
+    /// The same as the Postgres `area(box)` function.
+    pub fn area(&self) -> f64 {
+        self.width() * self.height()
+    }
+
+    /// The total length of the box's edges. Postgres has no function for this, but it matches
+    /// `length(path(polygon(box)))`.
+    pub fn perimeter(&self) -> f64 {
+        2.0 * (self.width() + self.height())
+    }
+
+    /// The same as the Postgres `center(box)` function.
+    pub fn center(&self) -> PgPoint {
+        let (low, high) = self.corners();
+        PgPoint((high.0 + low.0) / 2.0, (high.1 + low.1) / 2.0)
+    }
+
+    /// The same as the Postgres `width(box)` function.
+    pub fn width(&self) -> f64 {
+        let (low, high) = self.corners();
+        high.0 - low.0
+    }
+
+    /// The same as the Postgres `height(box)` function.
+    pub fn height(&self) -> f64 {
+        let (low, high) = self.corners();
+        high.1 - low.1
+    }
+
+    /// This box with its corners reordered to (lower left, upper right), the same as the Postgres
+    /// `bound_box(box, box)` function given this box twice.
+    pub fn bounding_box(&self) -> PgBox {
+        PgBox::new(self.0, self.1)
+    }
+
+    /// The distance from `other` to the nearest edge of this box, or 0 if the box contains the
+    /// point. The same as the Postgres `<->` operator.
+    pub fn distance_to_point(&self, other: &PgPoint) -> f64 {
+        // Port of `box_closept_point`, which measures the distance to each edge in turn.
+        if self.contains_point(other) {
+            return 0.0;
+        }
+        let (low, high) = self.corners();
+        let top_left = PgPoint(low.0, high.1);
+        let bottom_right = PgPoint(high.0, low.1);
+        let edges = [
+            PgLineSegment(high, top_left),
+            PgLineSegment(low, bottom_right),
+            PgLineSegment(high, bottom_right),
+        ];
+        let first = lseg_closept_point(PgLineSegment(low, top_left), *other);
+        edges.iter().fold(first, |dist, &edge| {
+            let d = lseg_closept_point(edge, *other);
+            if float8_lt(d, dist) {
+                d
+            } else {
+                dist
+            }
+        })
+    }
+
+    /// The same as the Postgres `<<` operator.
+    pub fn is_strictly_left_of(&self, other: &PgBox) -> bool {
+        fp_lt(self.high().0, other.low().0)
+    }
+
+    /// The same as the Postgres `>>` operator.
+    pub fn is_strictly_right_of(&self, other: &PgBox) -> bool {
+        fp_gt(self.low().0, other.high().0)
+    }
+
+    /// The same as the Postgres `<<|` operator.
+    pub fn is_strictly_below(&self, other: &PgBox) -> bool {
+        fp_lt(self.high().1, other.low().1)
+    }
+
+    /// The same as the Postgres `|>>` operator.
+    pub fn is_strictly_above(&self, other: &PgBox) -> bool {
+        fp_gt(self.low().1, other.high().1)
+    }
+
+    /// The same as the Postgres `<^` operator, which allows the boxes to touch.
+    pub fn is_below(&self, other: &PgBox) -> bool {
+        fp_le(self.high().1, other.low().1)
+    }
+
+    /// The same as the Postgres `>^` operator, which allows the boxes to touch.
+    pub fn is_above(&self, other: &PgBox) -> bool {
+        fp_ge(self.low().1, other.high().1)
+    }
+
+    /// The same as the Postgres `&<` operator.
+    pub fn does_not_extend_right_of(&self, other: &PgBox) -> bool {
+        fp_le(self.high().0, other.high().0)
+    }
+
+    /// The same as the Postgres `&>` operator.
+    pub fn does_not_extend_left_of(&self, other: &PgBox) -> bool {
+        fp_ge(self.low().0, other.low().0)
+    }
+
+    /// The same as the Postgres `&<|` operator.
+    pub fn does_not_extend_above(&self, other: &PgBox) -> bool {
+        fp_le(self.high().1, other.high().1)
+    }
+
+    /// The same as the Postgres `|&>` operator.
+    pub fn does_not_extend_below(&self, other: &PgBox) -> bool {
+        fp_ge(self.low().1, other.low().1)
+    }
+}
+
+impl PgCircle {
+    /// True if this circle contains `other`, the same as the Postgres `@>` operator.
+    pub fn contains(&self, other: &PgCircle) -> bool {
+        fp_le(point_dt(self.0, other.0), self.1 - other.1)
+    }
+
+    /// True if this circle contains `other`, the same as the Postgres `@>` operator. Points on
+    /// the edge of the circle are contained, without any tolerance.
+    pub fn contains_point(&self, other: &PgPoint) -> bool {
+        point_dt(self.0, *other) <= self.1
+    }
+
+    /// True if `other` contains this circle, the same as the Postgres `<@` operator.
+    pub fn is_contained_by(&self, other: &PgCircle) -> bool {
+        other.contains(self)
+    }
+
+    /// True if the circles overlap, the same as the Postgres `&&` operator.
+    pub fn overlaps_with(&self, other: &PgCircle) -> bool {
+        fp_le(point_dt(self.0, other.0), self.1 + other.1)
+    }
+
+    /// The distance between the edges of the circles, or 0 if they overlap. The same as the
+    /// Postgres `<->` operator.
+    pub fn distance(&self, other: &PgCircle) -> f64 {
+        let result = point_dt(self.0, other.0) - (self.1 + other.1);
+        if result < 0.0 {
+            0.0
+        } else {
+            result
+        }
+    }
+
+    /// The distance from `other` to the edge of this circle, or 0 if the circle contains the
+    /// point. The same as the Postgres `<->` operator.
+    pub fn distance_to_point(&self, other: &PgPoint) -> f64 {
+        let result = point_dt(*other, self.0) - self.1;
+        if result < 0.0 {
+            0.0
+        } else {
+            result
+        }
+    }
+
+    /// The same as the Postgres `area(circle)` function.
+    pub fn area(&self) -> f64 {
+        self.1 * self.1 * ::std::f64::consts::PI
+    }
+
+    /// The circumference of the circle. Postgres has no function for this.
+    pub fn perimeter(&self) -> f64 {
+        2.0 * ::std::f64::consts::PI * self.1
+    }
+
+    /// The same as the Postgres `center(circle)` function.
+    pub fn center(&self) -> PgPoint {
+        self.0
+    }
+
+    /// The same as the Postgres `diameter(circle)` function.
+    pub fn diameter(&self) -> f64 {
+        self.1 * 2.0
+    }
+
+    /// The width of the circle's bounding box, which is its diameter. Postgres has no function
+    /// for this; `width(box(circle))` is the width of the largest box inside the circle.
+    pub fn width(&self) -> f64 {
+        self.diameter()
+    }
+
+    /// The height of the circle's bounding box, which is its diameter. Postgres has no function
+    /// for this; `height(box(circle))` is the height of the largest box inside the circle.
+    pub fn height(&self) -> f64 {
+        self.diameter()
+    }
+
+    /// The smallest box containing the circle, from (lower left, upper right).
+    ///
+    /// This is not the same as the Postgres `box(circle)` function, which returns the largest box
+    /// inside the circle.
+    pub fn bounding_box(&self) -> PgBox {
+        let PgCircle(PgPoint(x, y), radius) = *self;
+        PgBox(PgPoint(x - radius, y - radius), PgPoint(x + radius, y + radius))
+    }
+
+    /// The same as the Postgres `<<` operator.
+    pub fn is_strictly_left_of(&self, other: &PgCircle) -> bool {
+        fp_lt((self.0).0 + self.1, (other.0).0 - other.1)
+    }
+
+    /// The same as the Postgres `>>` operator.
+    pub fn is_strictly_right_of(&self, other: &PgCircle) -> bool {
+        fp_gt((self.0).0 - self.1, (other.0).0 + other.1)
+    }
+
+    /// The same as the Postgres `<<|` operator.
+    pub fn is_strictly_below(&self, other: &PgCircle) -> bool {
+        fp_lt((self.0).1 + self.1, (other.0).1 - other.1)
+    }
+
+    /// The same as the Postgres `|>>` operator.
+    pub fn is_strictly_above(&self, other: &PgCircle) -> bool {
+        fp_gt((self.0).1 - self.1, (other.0).1 + other.1)
+    }
+
+    /// The same as the Postgres `&<` operator.
+    pub fn does_not_extend_right_of(&self, other: &PgCircle) -> bool {
+        fp_le((self.0).0 + self.1, (other.0).0 + other.1)
+    }
+
+    /// The same as the Postgres `&>` operator.
+    pub fn does_not_extend_left_of(&self, other: &PgCircle) -> bool {
+        fp_ge((self.0).0 - self.1, (other.0).0 - other.1)
+    }
+
+    /// The same as the Postgres `&<|` operator.
+    pub fn does_not_extend_above(&self, other: &PgCircle) -> bool {
+        fp_le((self.0).1 + self.1, (other.0).1 + other.1)
+    }
+
+    /// The same as the Postgres `|&>` operator.
+    pub fn does_not_extend_below(&self, other: &PgCircle) -> bool {
+        fp_ge((self.0).1 - self.1, (other.0).1 - other.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use diesel::dsl::select;
+    use diesel::prelude::*;
+
+    use expression_methods::*;
+    use pg::types::geometric::{PgBox, PgCircle, PgPoint};
+    use sql_types::{self, Circle, Point};
+    use test_helpers::connection;
+
+    // Coordinates on, near and within `EPSILON` of the edges of the shapes below.
+    const COORDINATES: [f64; 6] = [-1., 0., 0.5, 1., 1.0000005, 2.];
+
+    fn points() -> Vec<PgPoint> {
+        let mut points = Vec::new();
+        for &x in &COORDINATES {
+            for &y in &COORDINATES {
+                points.push(PgPoint(x, y));
+            }
+        }
+        points
+    }
+
+    fn boxes() -> Vec<PgBox> {
+        vec![
+            PgBox(PgPoint(0., 0.), PgPoint(1., 1.)),
+            PgBox(PgPoint(0.5, 0.5), PgPoint(2., 2.)),
+            PgBox(PgPoint(1.0000005, 0.), PgPoint(2., 1.)),
+            PgBox(PgPoint(-1., -1.), PgPoint(2., 2.)),
+            PgBox(PgPoint(1., 1.), PgPoint(2., 2.)),
+            PgBox(PgPoint(0., 1.0000005), PgPoint(1., 2.)),
+            // Corners which Postgres reorders when it receives the box.
+            PgBox(PgPoint(2., 0.), PgPoint(0.5, 1.)),
+        ]
+    }
+
+    fn circles() -> Vec<PgCircle> {
+        vec![
+            PgCircle(PgPoint(0., 0.), 1.),
+            PgCircle(PgPoint(1., 1.), 0.5),
+            PgCircle(PgPoint(2., 0.), 1.),
+            PgCircle(PgPoint(0., 0.), 3.),
+            PgCircle(PgPoint(0.5, 0.), 0.4999995),
+            PgCircle(PgPoint(1., 0.), 0.),
+            // Large radii whose difference is within `EPSILON` of the distance between the
+            // centers, where the rounding of the subtraction decides containment.
+            PgCircle(PgPoint(0., 0.), 1000000085.),
+            PgCircle(PgPoint(2.0000010046303505, 0.), 1000000083.),
+        ]
+    }
+
+    #[test]
+    fn point_operators_match_postgres() {
+        let connection = connection();
+        let references = [PgPoint(0., 0.), PgPoint(1., 1.), PgPoint(0.5, 1.0000005)];
+        for point in points() {
+            for other in &references {
+                let expected = select((
+                    point.into_sql::<Point>().distance(other.into_sql::<Point>()),
+                    point.into_sql::<Point>().is_strictly_left_of(other),
+                    point.into_sql::<Point>().is_strictly_right_of(other),
+                    point.into_sql::<Point>().is_strictly_below(other),
+                    point.into_sql::<Point>().is_strictly_above(other),
+                    point.into_sql::<Point>().is_below(other),
+                    point.into_sql::<Point>().is_above(other),
+                )).get_result(&connection);
+                let actual = (
+                    point.distance(other),
+                    point.is_strictly_left_of(other),
+                    point.is_strictly_right_of(other),
+                    point.is_strictly_below(other),
+                    point.is_strictly_above(other),
+                    point.is_below(other),
+                    point.is_above(other),
+                );
+                assert_eq!(expected, Ok(actual), "{:?} and {:?}", point, other);
+            }
+        }
+    }
+
+    #[test]
+    fn point_and_box_operators_match_postgres() {
+        let connection = connection();
+        for point in points() {
+            for other in boxes() {
+                let expected = select((
+                    point
+                        .into_sql::<Point>()
+                        .distance(other.into_sql::<sql_types::Box>()),
+                    point
+                        .into_sql::<Point>()
+                        .is_contained_by(other.into_sql::<sql_types::Box>()),
+                )).get_result(&connection);
+                let actual = (point.distance_to_box(&other), point.is_contained_by_box(&other));
+                assert_eq!(expected, Ok(actual), "{:?} and {:?}", point, other);
+            }
+        }
+    }
+
+    #[test]
+    fn point_and_circle_operators_match_postgres() {
+        let connection = connection();
+        for point in points() {
+            for other in circles() {
+                let expected = select((
+                    point
+                        .into_sql::<Point>()
+                        .distance(other.into_sql::<Circle>()),
+                    point
+                        .into_sql::<Point>()
+                        .is_contained_by(other.into_sql::<Circle>()),
+                )).get_result(&connection);
+                let actual = (
+                    point.distance_to_circle(&other),
+                    point.is_contained_by_circle(&other),
+                );
+                assert_eq!(expected, Ok(actual), "{:?} and {:?}", point, other);
+            }
+        }
+    }
+
+    #[test]
+    fn box_operators_match_postgres() {
+        let connection = connection();
+        for a in boxes() {
+            for b in boxes() {
+                let bound = a.into_sql::<sql_types::Box>();
+                let expected = select((
+                    bound.contains(b.into_sql::<sql_types::Box>()),
+                    bound.is_contained_by(b),
+                    bound.overlaps_with(b),
+                    bound.distance(b.into_sql::<sql_types::Box>()),
+                    bound.is_below(b),
+                    bound.is_above(b),
+                )).get_result(&connection);
+                let actual = (
+                    a.contains(&b),
+                    a.is_contained_by(&b),
+                    a.overlaps_with(&b),
+                    a.distance(&b),
+                    a.is_below(&b),
+                    a.is_above(&b),
+                );
+                assert_eq!(expected, Ok(actual), "{:?} and {:?}", a, b);
+
+                let expected = select((
+                    bound.is_strictly_left_of(b),
+                    bound.is_strictly_right_of(b),
+                    bound.is_strictly_below(b),
+                    bound.is_strictly_above(b),
+                    bound.does_not_extend_right_of(b),
+                    bound.does_not_extend_left_of(b),
+                    bound.does_not_extend_above(b),
+                    bound.does_not_extend_below(b),
+                )).get_result(&connection);
+                let actual = (
+                    a.is_strictly_left_of(&b),
+                    a.is_strictly_right_of(&b),
+                    a.is_strictly_below(&b),
+                    a.is_strictly_above(&b),
+                    a.does_not_extend_right_of(&b),
+                    a.does_not_extend_left_of(&b),
+                    a.does_not_extend_above(&b),
+                    a.does_not_extend_below(&b),
+                );
+                assert_eq!(expected, Ok(actual), "{:?} and {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn circle_operators_match_postgres() {
+        let connection = connection();
+        for a in circles() {
+            for b in circles() {
+                let bound = a.into_sql::<Circle>();
+                let expected = select((
+                    bound.contains(b.into_sql::<Circle>()),
+                    bound.is_contained_by(b),
+                    bound.overlaps_with(b),
+                    bound.distance(b.into_sql::<Circle>()),
+                    bound.is_strictly_left_of(b),
+                    bound.is_strictly_right_of(b),
+                    bound.is_strictly_below(b),
+                    bound.is_strictly_above(b),
+                    bound.does_not_extend_right_of(b),
+                    bound.does_not_extend_left_of(b),
+                    bound.does_not_extend_above(b),
+                    bound.does_not_extend_below(b),
+                )).get_result(&connection);
+                let actual = (
+                    a.contains(&b),
+                    a.is_contained_by(&b),
+                    a.overlaps_with(&b),
+                    a.distance(&b),
+                    a.is_strictly_left_of(&b),
+                    a.is_strictly_right_of(&b),
+                    a.is_strictly_below(&b),
+                    a.is_strictly_above(&b),
+                    a.does_not_extend_right_of(&b),
+                    a.does_not_extend_left_of(&b),
+                    a.does_not_extend_above(&b),
+                    a.does_not_extend_below(&b),
+                );
+                assert_eq!(expected, Ok(actual), "{:?} and {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn box_metrics_match_postgres() {
+        use dsl::{area, bound_box, center, height, length, to_path, to_polygon, width};
+
+        let connection = connection();
+        for b in boxes() {
+            let bound = b.into_sql::<sql_types::Box>();
+            let expected = select((
+                area(bound),
+                length(to_path(to_polygon(bound))),
+                center(bound),
+                width(bound),
+                height(bound),
+                bound_box(bound, bound),
+            )).get_result(&connection);
+            let actual = (
+                b.area(),
+                b.perimeter(),
+                b.center(),
+                b.width(),
+                b.height(),
+                b.bounding_box(),
+            );
+            assert_eq!(expected, Ok(actual), "{:?}", b);
+        }
+    }
+
+    #[test]
+    fn circle_and_point_metrics_match_postgres() {
+        use dsl::{area, center, diameter, height, to_box, width};
+
+        let connection = connection();
+        for c in circles() {
+            let bound = c.into_sql::<Circle>();
+            let expected = select((area(bound), center(bound), diameter(bound)))
+                .get_result(&connection);
+            assert_eq!(expected, Ok((c.area(), c.center(), c.diameter())), "{:?}", c);
+            let bounding_box = c.bounding_box();
+            assert!(super::fp_eq(c.width(), bounding_box.width()), "{:?}", c);
+            assert!(super::fp_eq(c.height(), bounding_box.height()), "{:?}", c);
+        }
+        assert_eq!(2.0 * ::std::f64::consts::PI, PgCircle(PgPoint(5., 5.), 1.).perimeter());
+        for point in points() {
+            let bounding_box = to_box(point.into_sql::<Point>());
+            let expected = select((
+                bounding_box,
+                area(bounding_box),
+                center(bounding_box),
+                width(bounding_box),
+                height(bounding_box),
+            )).get_result(&connection);
+            let actual = (
+                point.bounding_box(),
+                point.area(),
+                point.center(),
+                point.width(),
+                point.height(),
+            );
+            assert_eq!(expected, Ok(actual), "{:?}", point);
+            assert_eq!(0.0, point.perimeter());
+        }
+    }
+}
@@ -1,6 +1,8 @@
 //! Support for Geometric types under PostgreSQL.
 
 use byteorder::{NetworkEndian, ReadBytesExt, WriteBytesExt};
+use std::error::Error;
+use std::fmt;
 use std::io::prelude::*;
 
 use diesel::deserialize::{self, FromSql};
@@ -9,7 +11,11 @@ use diesel::expression::AsExpression;
 use diesel::pg::Pg;
 use diesel::serialize::{self, IsNull, Output, ToSql};
 use diesel::sql_types::Nullable;
-use sql_types::{self, Circle, Point};
+use sql_types::{self, Circle, Line, Lseg, Path, Point, Polygon};
+
+use super::geo_ops::{float8_gt, fp_eq, point_eq_point, point_sl};
+#[cfg(feature = "validation")]
+use super::validation::Validate;
 
 /// Point is represented in Postgres as a tuple of 64 bit floating point values (x, y).  This
 /// struct is a dumb wrapper type, meant only to indicate the tuple's meaning.
@@ -21,6 +27,7 @@ pub struct PgPoint(pub f64, pub f64);
 impl FromSql<Point, Pg> for PgPoint {
     fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
         let mut bytes = not_none!(bytes);
+        check_len("point", bytes, 16)?;
         let x = bytes.read_f64::<NetworkEndian>()?;
         let y = bytes.read_f64::<NetworkEndian>()?;
         Ok(PgPoint(x, y))
@@ -29,6 +36,8 @@ impl FromSql<Point, Pg> for PgPoint {
 
 impl ToSql<Point, Pg> for PgPoint {
     fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        #[cfg(feature = "validation")]
+        self.validate()?;
         out.write_f64::<NetworkEndian>(self.0)?;
         out.write_f64::<NetworkEndian>(self.1)?;
         Ok(IsNull::No)
@@ -37,6 +46,22 @@ impl ToSql<Point, Pg> for PgPoint {
 
 /// Box is represented in Postgres as a tuple of points `(lower left, upper
 /// right)`. This struct is a dumb wrapper type, meant only to indicate the tuple's meaning.
+///
+/// Postgres reorders the coordinates of every box it receives so that its corners follow this
+/// convention. [`PgBox::new`] does the same, and `FromSql` only ever returns boxes in this
+/// canonical form, so a box built with `PgBox::new` reads back equal to the one written. A box
+/// built directly from the tuple with its corners in another order reads back as the equivalent
+/// canonical box.
+///
+/// ```rust
+/// # extern crate diesel_geometry;
+/// # use diesel_geometry::data_types::{PgBox, PgPoint};
+/// # fn main() {
+/// let b = PgBox::new(PgPoint(2., 0.), PgPoint(0., 3.));
+/// assert_eq!(PgBox(PgPoint(0., 0.), PgPoint(2., 3.)), b);
+/// assert_eq!((PgPoint(0., 0.), PgPoint(2., 3.)), b.corners());
+/// # }
+/// ```
 #[derive(Debug, Clone, PartialEq, Copy, FromSqlRow)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(AsExpression)]
@@ -83,22 +108,26 @@ pub struct PgBox(pub PgPoint, pub PgPoint);
 impl FromSql<sql_types::Box, Pg> for PgBox {
     fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
         let bytes = not_none!(bytes);
+        check_len("box", bytes, 32)?;
         let (upper_bytes, lower_bytes) = bytes.split_at(16);
         // By convention the box is written as (lower left, upper right) and is stored as [ high.x,
         // high,y, low.x, low.y ].
-        let upper = PgPoint::from_sql(Some(upper_bytes))?;
-        let lower = PgPoint::from_sql(Some(lower_bytes))?;
+        let upper = <PgPoint as FromSql<Point, Pg>>::from_sql(Some(upper_bytes))?;
+        let lower = <PgPoint as FromSql<Point, Pg>>::from_sql(Some(lower_bytes))?;
         Ok(PgBox(lower, upper))
     }
 }
 
 impl ToSql<sql_types::Box, Pg> for PgBox {
     fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        #[cfg(feature = "validation")]
+        self.validate()?;
         // By convention the box is written as (lower left, upper right)
-        // and is stored as [ high.x, high,y, low.x, low.y ]. Postgres will reorder the corners if
-        // necessary. We write the points assuming the Box is following convention.
-        <PgPoint as ToSql<Point, Pg>>::to_sql(&self.1, out)?;
-        <PgPoint as ToSql<Point, Pg>>::to_sql(&self.0, out)?;
+        // and is stored as [ high.x, high,y, low.x, low.y ]. We write the canonical corners, which
+        // is what Postgres would reorder them to anyway.
+        let (low, high) = self.corners();
+        <PgPoint as ToSql<Point, Pg>>::to_sql(&high, out)?;
+        <PgPoint as ToSql<Point, Pg>>::to_sql(&low, out)?;
 
         Ok(IsNull::No)
     }
@@ -120,8 +149,9 @@ pub struct PgCircle(pub PgPoint, pub f64);
 impl FromSql<sql_types::Circle, Pg> for PgCircle {
     fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
         let bytes = not_none!(bytes);
+        check_len("circle", bytes, 24)?;
         let (center_bytes, mut radius_bytes) = bytes.split_at(16);
-        let center = PgPoint::from_sql(Some(center_bytes))?;
+        let center = <PgPoint as FromSql<Point, Pg>>::from_sql(Some(center_bytes))?;
         let radius = radius_bytes.read_f64::<NetworkEndian>()?;
         Ok(PgCircle(center, radius))
     }
@@ -129,6 +159,8 @@ impl FromSql<sql_types::Circle, Pg> for PgCircle {
 
 impl ToSql<sql_types::Circle, Pg> for PgCircle {
     fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        #[cfg(feature = "validation")]
+        self.validate()?;
         <PgPoint as ToSql<Point, Pg>>::to_sql(&self.0, out)?;
         out.write_f64::<NetworkEndian>(self.1)?;
 
@@ -136,8 +168,356 @@ impl ToSql<sql_types::Circle, Pg> for PgCircle {
     }
 }
 
+/// Lseg is represented in Postgres as a tuple of end points `(start, end)`. This struct is a dumb
+/// wrapper type, meant only to indicate the tuple's meaning.
+#[derive(Debug, Clone, PartialEq, Copy, FromSqlRow, AsExpression)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[sql_type = "Lseg"]
+pub struct PgLineSegment(pub PgPoint, pub PgPoint);
+
+impl FromSql<Lseg, Pg> for PgLineSegment {
+    fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
+        let bytes = not_none!(bytes);
+        check_len("lseg", bytes, 32)?;
+        let (start_bytes, end_bytes) = bytes.split_at(16);
+        let start = <PgPoint as FromSql<Point, Pg>>::from_sql(Some(start_bytes))?;
+        let end = <PgPoint as FromSql<Point, Pg>>::from_sql(Some(end_bytes))?;
+        Ok(PgLineSegment(start, end))
+    }
+}
+
+impl ToSql<Lseg, Pg> for PgLineSegment {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        #[cfg(feature = "validation")]
+        self.validate()?;
+        <PgPoint as ToSql<Point, Pg>>::to_sql(&self.0, out)?;
+        <PgPoint as ToSql<Point, Pg>>::to_sql(&self.1, out)?;
+
+        Ok(IsNull::No)
+    }
+}
+
+/// Line is represented in Postgres as the coefficients of the linear equation `Ax + By + C = 0`.
+/// Postgres rejects lines where `a` and `b` are both zero.
+#[derive(Debug, Clone, PartialEq, Copy, FromSqlRow, AsExpression)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[sql_type = "Line"]
+pub struct PgLine {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+}
+
+impl PgLine {
+    /// Constructs the line passing through both points, using the same coefficients as the
+    /// Postgres `line(point, point)` function.
+    ///
+    /// Like Postgres, points whose x coordinates are within `1.0E-06` of each other produce a
+    /// vertical line, and points whose y coordinates are that close produce a horizontal line.
+    /// Returns `None` if the points are the same within that tolerance, which Postgres rejects as
+    /// "invalid line specification: must be two distinct points".
+    pub fn from_points(p1: PgPoint, p2: PgPoint) -> Option<Self> {
+        if point_eq_point(p1, p2) {
+            return None;
+        }
+        Some(PgLine::from_point_slope(p1, point_sl(p1, p2)))
+    }
+
+    /// Constructs the line `y = slope * x + intercept`.
+    ///
+    /// An infinite slope produces the vertical line `x = intercept`.
+    pub fn from_slope_intercept(slope: f64, intercept: f64) -> Self {
+        if slope.is_infinite() {
+            PgLine::from_point_slope(PgPoint(intercept, 0.0), slope)
+        } else {
+            PgLine::from_point_slope(PgPoint(0.0, intercept), slope)
+        }
+    }
+
+    // Port of `line_construct` from geo_ops.c.
+    pub(super) fn from_point_slope(point: PgPoint, slope: f64) -> Self {
+        if slope.is_infinite() {
+            PgLine {
+                a: -1.0,
+                b: 0.0,
+                c: point.0,
+            }
+        } else if slope == 0.0 {
+            PgLine {
+                a: 0.0,
+                b: -1.0,
+                c: point.1,
+            }
+        } else {
+            let c = point.1 - slope * point.0;
+            PgLine {
+                a: slope,
+                b: -1.0,
+                // Avoid producing -0, the same as Postgres.
+                c: if c == 0.0 { 0.0 } else { c },
+            }
+        }
+    }
+}
+
+impl FromSql<Line, Pg> for PgLine {
+    fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
+        let mut bytes = not_none!(bytes);
+        check_len("line", bytes, 24)?;
+        let a = bytes.read_f64::<NetworkEndian>()?;
+        let b = bytes.read_f64::<NetworkEndian>()?;
+        let c = bytes.read_f64::<NetworkEndian>()?;
+        Ok(PgLine { a, b, c })
+    }
+}
+
+impl ToSql<Line, Pg> for PgLine {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        #[cfg(feature = "validation")]
+        self.validate()?;
+        out.write_f64::<NetworkEndian>(self.a)?;
+        out.write_f64::<NetworkEndian>(self.b)?;
+        out.write_f64::<NetworkEndian>(self.c)?;
+
+        Ok(IsNull::No)
+    }
+}
+
+/// Path is represented in Postgres as a list of points, and a flag indicating whether the last
+/// point connects back to the first one. This struct is a dumb wrapper type, meant only to
+/// indicate the meaning of the list.
+#[derive(Debug, Clone, PartialEq, FromSqlRow, AsExpression)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[sql_type = "Path"]
+pub struct PgPath {
+    pub closed: bool,
+    pub points: Vec<PgPoint>,
+}
+
+impl FromSql<Path, Pg> for PgPath {
+    fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
+        let bytes = not_none!(bytes);
+        // Stored as [ closed, npts, p[0].x, p[0].y, ... ], see path_send in geo_ops.c.
+        let points = read_points("path", bytes, 1)?;
+        let closed = bytes[0] != 0;
+        Ok(PgPath { closed, points })
+    }
+}
+
+impl ToSql<Path, Pg> for PgPath {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        #[cfg(feature = "validation")]
+        self.validate()?;
+        out.write_u8(self.closed as u8)?;
+        write_points(&self.points, out)?;
+
+        Ok(IsNull::No)
+    }
+}
+
+/// Polygon is represented in Postgres as a list of vertices, where the last vertex connects back
+/// to the first one. This struct is a dumb wrapper type, meant only to indicate the list's
+/// meaning.
+#[derive(Debug, Clone, PartialEq, FromSqlRow, AsExpression)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[sql_type = "Polygon"]
+pub struct PgPolygon(pub Vec<PgPoint>);
+
+impl FromSql<Polygon, Pg> for PgPolygon {
+    fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
+        let bytes = not_none!(bytes);
+        // Postgres keeps the bounding box of a polygon next to its vertices, but only the
+        // vertices are sent as [ npts, p[0].x, p[0].y, ... ]. The receiving side recomputes the
+        // bounding box, see poly_send and poly_recv in geo_ops.c.
+        let points = read_points("polygon", bytes, 0)?;
+        Ok(PgPolygon(points))
+    }
+}
+
+impl ToSql<Polygon, Pg> for PgPolygon {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        #[cfg(feature = "validation")]
+        self.validate()?;
+        write_points(&self.0, out)
+    }
+}
+
+/// The error returned when Postgres sends a geometric value whose length doesn't match its type.
+///
+/// ```rust
+/// # extern crate diesel;
+/// # extern crate diesel_geometry;
+/// # use diesel::deserialize::FromSql;
+/// # use diesel_geometry::data_types::{GeometryDecodeError, PgBox};
+/// # use diesel_geometry::sql_types::Box;
+/// # fn main() {
+/// let error = <PgBox as FromSql<Box, _>>::from_sql(Some(&[0; 20])).unwrap_err();
+/// assert_eq!(
+///     Some(&GeometryDecodeError {
+///         type_name: "box",
+///         expected_len: 32,
+///         actual_len: 20,
+///     }),
+///     error.downcast_ref::<GeometryDecodeError>()
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GeometryDecodeError {
+    /// The name of the SQL type being decoded.
+    pub type_name: &'static str,
+    /// The number of bytes a value of the type should have. For paths and polygons this is
+    /// computed from the point count, or `usize::MAX` when the point count is negative.
+    pub expected_len: usize,
+    /// The number of bytes received.
+    pub actual_len: usize,
+}
+
+impl fmt::Display for GeometryDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Invalid {} value: expected {} bytes, but received {}",
+            self.type_name, self.expected_len, self.actual_len
+        )
+    }
+}
+
+impl Error for GeometryDecodeError {}
+
+fn check_len(
+    type_name: &'static str,
+    bytes: &[u8],
+    expected_len: usize,
+) -> Result<(), GeometryDecodeError> {
+    if bytes.len() == expected_len {
+        Ok(())
+    } else {
+        Err(GeometryDecodeError {
+            type_name,
+            expected_len,
+            actual_len: bytes.len(),
+        })
+    }
+}
+
+/// Reads the point count at `count_offset` followed by exactly that many points, which is how
+/// Postgres sends the variable length geometric types.
+fn read_points(
+    type_name: &'static str,
+    bytes: &[u8],
+    count_offset: usize,
+) -> deserialize::Result<Vec<PgPoint>> {
+    let header_len = count_offset + 4;
+    if bytes.len() < header_len {
+        return Err(GeometryDecodeError {
+            type_name,
+            expected_len: header_len,
+            actual_len: bytes.len(),
+        }.into());
+    }
+    let num_points = (&bytes[count_offset..]).read_i32::<NetworkEndian>()?;
+    // A negative count can't match any length, and must not be trusted for allocation.
+    let expected_len = if num_points < 0 {
+        usize::MAX
+    } else {
+        (num_points as usize)
+            .checked_mul(16)
+            .and_then(|len| len.checked_add(header_len))
+            .unwrap_or(usize::MAX)
+    };
+    check_len(type_name, bytes, expected_len)?;
+    bytes[header_len..]
+        .chunks(16)
+        .map(|point| <PgPoint as FromSql<Point, Pg>>::from_sql(Some(point)))
+        .collect()
+}
+
+fn write_points<W: Write>(points: &[PgPoint], out: &mut Output<W, Pg>) -> serialize::Result {
+    if points.len() > i32::MAX as usize {
+        return Err("Too many points to send to Postgres".into());
+    }
+    out.write_i32::<NetworkEndian>(points.len() as i32)?;
+    for point in points {
+        <PgPoint as ToSql<Point, Pg>>::to_sql(point, out)?;
+    }
+    Ok(IsNull::No)
+}
+
+/// Approximate equality between geometric values, following the rules of the Postgres `~=`
+/// operator.
+///
+/// Coordinates are compared with a tolerance of `1.0E-06`, like `FPeq` in Postgres, so two values
+/// which are `approx_eq` in Rust are also `same_as` each other in the database.
+///
+/// ```rust
+/// # extern crate diesel_geometry;
+/// # use diesel_geometry::data_types::{ApproxEq, PgPoint};
+/// # fn main() {
+/// assert!(PgPoint(1.0, 2.0).approx_eq(&PgPoint(1.0000001, 2.0)));
+/// assert!(!PgPoint(1.0, 2.0).approx_eq(&PgPoint(1.00001, 2.0)));
+/// # }
+/// ```
+pub trait ApproxEq {
+    /// Returns true if `self` and `other` are the same within Postgres' tolerance.
+    fn approx_eq(&self, other: &Self) -> bool;
+}
+
+/// Mirrors `point_eq_point`: if any coordinate is NaN, the points must be exactly equal.
+impl ApproxEq for PgPoint {
+    fn approx_eq(&self, other: &Self) -> bool {
+        point_eq_point(*self, *other)
+    }
+}
+
+/// Mirrors `box_same`, comparing the canonical corners of the boxes.
+impl ApproxEq for PgBox {
+    fn approx_eq(&self, other: &Self) -> bool {
+        let (low1, high1) = self.corners();
+        let (low2, high2) = other.corners();
+        high1.approx_eq(&high2) && low1.approx_eq(&low2)
+    }
+}
+
+/// Mirrors `circle_same`.
+impl ApproxEq for PgCircle {
+    fn approx_eq(&self, other: &Self) -> bool {
+        let same_radius = (self.1.is_nan() && other.1.is_nan()) || fp_eq(self.1, other.1);
+        same_radius && self.0.approx_eq(&other.0)
+    }
+}
+
+impl PgBox {
+    /// Constructs the box with opposite corners `a` and `b`, reordering their coordinates into
+    /// `(lower left, upper right)` the same way Postgres does. As in Postgres, NaN sorts above
+    /// every other value.
+    pub fn new(a: PgPoint, b: PgPoint) -> Self {
+        let (low_x, high_x) = if float8_gt(a.0, b.0) { (b.0, a.0) } else { (a.0, b.0) };
+        let (low_y, high_y) = if float8_gt(a.1, b.1) { (b.1, a.1) } else { (a.1, b.1) };
+        PgBox(PgPoint(low_x, low_y), PgPoint(high_x, high_y))
+    }
+
+    /// The lower left corner of the box, whichever order the tuple's corners are in.
+    pub fn low(&self) -> PgPoint {
+        self.corners().0
+    }
+
+    /// The upper right corner of the box, whichever order the tuple's corners are in.
+    pub fn high(&self) -> PgPoint {
+        self.corners().1
+    }
+
+    /// The `(lower left, upper right)` corners of the box, whichever order the tuple's corners are
+    /// in.
+    pub fn corners(&self) -> (PgPoint, PgPoint) {
+        let PgBox(low, high) = PgBox::new(self.0, self.1);
+        (low, high)
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use byteorder::{ByteOrder, NetworkEndian, ReadBytesExt};
     use diesel;
     use diesel::deserialize::FromSql;
     use diesel::dsl::sql;
@@ -147,19 +527,189 @@ mod tests {
     use diesel::serialize::ToSql;
 
     use expression_methods::*;
-    use pg::types::geometric::{PgBox, PgCircle, PgPoint};
-    use sql_types::{self, Circle, Point};
+    use pg::types::geometric::{
+        ApproxEq, GeometryDecodeError, PgBox, PgCircle, PgLine, PgLineSegment, PgPath, PgPoint,
+        PgPolygon,
+    };
+    use sql_types::{self, Circle, Line, Lseg, Path, Point, Polygon};
     use test_helpers::{connection, create_testing_output};
 
+    use super::super::geo_ops::fp_eq;
+
     #[test]
     fn point_roundtrip() {
         let mut bytes = create_testing_output();
         let input_point = PgPoint(4.5, 3439.1);
         ToSql::<Point, Pg>::to_sql(&input_point, &mut bytes).unwrap();
-        let output_point: PgPoint = FromSql::from_sql(Some(bytes.as_ref())).unwrap();
+        let output_point: PgPoint = FromSql::<Point, Pg>::from_sql(Some(bytes.as_ref())).unwrap();
         assert_eq!(input_point, output_point);
     }
 
+    #[test]
+    fn point_from_sql_names_the_sql_type() {
+        use diesel::sql_types::Text;
+
+        let mut bytes = create_testing_output();
+        let input_point = PgPoint(4.5, 3439.1);
+        ToSql::<Point, Pg>::to_sql(&input_point, &mut bytes).unwrap();
+        let binary = <PgPoint as FromSql<Point, Pg>>::from_sql(Some(bytes.as_ref()));
+        assert_eq!(input_point, binary.unwrap());
+        let text = <PgPoint as FromSql<Text, Pg>>::from_sql(Some(&b"(4.5,3439.1)"[..]));
+        assert_eq!(input_point, text.unwrap());
+    }
+
+    #[test]
+    fn lseg_binary_roundtrip() {
+        let mut bytes = create_testing_output();
+        let input_segment = PgLineSegment(PgPoint(-1.5, 2.0), PgPoint(7.25, 3439.1));
+        ToSql::<Lseg, Pg>::to_sql(&input_segment, &mut bytes).unwrap();
+        let output_segment: PgLineSegment =
+            FromSql::<Lseg, Pg>::from_sql(Some(bytes.as_ref())).unwrap();
+        assert_eq!(input_segment, output_segment);
+    }
+
+    #[test]
+    fn line_binary_roundtrip() {
+        let mut bytes = create_testing_output();
+        let input_line = PgLine {
+            a: 1.5,
+            b: -1.0,
+            c: 3439.1,
+        };
+        ToSql::<Line, Pg>::to_sql(&input_line, &mut bytes).unwrap();
+        let output_line: PgLine = FromSql::<Line, Pg>::from_sql(Some(bytes.as_ref())).unwrap();
+        assert_eq!(input_line, output_line);
+    }
+
+    #[test]
+    fn path_binary_roundtrip() {
+        let mut bytes = create_testing_output();
+        let input_path = PgPath {
+            closed: true,
+            points: vec![PgPoint(4.5, 3439.1), PgPoint(-1., 0.), PgPoint(2., 2.)],
+        };
+        ToSql::<Path, Pg>::to_sql(&input_path, &mut bytes).unwrap();
+        let output_path: PgPath = FromSql::<Path, Pg>::from_sql(Some(bytes.as_ref())).unwrap();
+        assert_eq!(input_path, output_path);
+    }
+
+    #[test]
+    fn path_from_sql_rejects_wrong_lengths() {
+        let mut bytes = create_testing_output();
+        let input_path = PgPath {
+            closed: false,
+            points: vec![PgPoint(4.5, 3439.1), PgPoint(-1., 0.)],
+        };
+        ToSql::<Path, Pg>::to_sql(&input_path, &mut bytes).unwrap();
+        let bytes: &[u8] = bytes.as_ref();
+        for len in 0..bytes.len() {
+            let path: Result<PgPath, _> = FromSql::<Path, Pg>::from_sql(Some(&bytes[..len]));
+            assert!(path.is_err(), "decoded a path from {} bytes", len);
+        }
+        let mut trailing = bytes.to_vec();
+        trailing.push(0);
+        let path: Result<PgPath, _> = FromSql::<Path, Pg>::from_sql(Some(&trailing));
+        assert!(path.is_err());
+        // A negative or absurd point count must not be trusted for allocation.
+        let huge_count = [0u8, 0x7f, 0xff, 0xff, 0xff];
+        let path: Result<PgPath, _> = FromSql::<Path, Pg>::from_sql(Some(&huge_count));
+        assert!(path.is_err());
+        let negative_count = [1u8, 0xff, 0xff, 0xff, 0xff];
+        let path: Result<PgPath, _> = FromSql::<Path, Pg>::from_sql(Some(&negative_count));
+        assert!(path.is_err());
+    }
+
+    fn check_decode<T, ST>(type_name: &str, bytes: &[u8], valid: bool)
+    where
+        T: FromSql<ST, Pg> + ::std::fmt::Debug,
+    {
+        match T::from_sql(Some(bytes)) {
+            Ok(value) => assert!(valid, "decoded {:?} from {:?}", value, bytes),
+            Err(e) => {
+                let error = e.downcast_ref::<GeometryDecodeError>().unwrap();
+                assert!(!valid, "{}", error);
+                assert_eq!(type_name, error.type_name);
+                assert_eq!(bytes.len(), error.actual_len);
+                assert_ne!(bytes.len(), error.expected_len);
+            }
+        }
+    }
+
+    fn check_decode_all(bytes: &[u8]) {
+        let len = bytes.len();
+        check_decode::<PgPoint, Point>("point", bytes, len == 16);
+        check_decode::<PgBox, sql_types::Box>("box", bytes, len == 32);
+        check_decode::<PgCircle, Circle>("circle", bytes, len == 24);
+        check_decode::<PgLineSegment, Lseg>("lseg", bytes, len == 32);
+        check_decode::<PgLine, Line>("line", bytes, len == 24);
+        let has_points = |count_offset: usize| {
+            let header_len = count_offset + 4;
+            len >= header_len && {
+                let count = (&bytes[count_offset..]).read_i32::<NetworkEndian>().unwrap();
+                count >= 0 && len == header_len + count as usize * 16
+            }
+        };
+        check_decode::<PgPath, Path>("path", bytes, has_points(1));
+        check_decode::<PgPolygon, Polygon>("polygon", bytes, has_points(0));
+    }
+
+    #[test]
+    fn from_sql_never_panics() {
+        // A xorshift generator, so the inputs are reproducible without pulling in a fuzzer.
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let mut next_byte = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        };
+        for len in 0..100 {
+            for _ in 0..20 {
+                let mut bytes = (0..len).map(|_| next_byte()).collect::<Vec<_>>();
+                check_decode_all(&bytes);
+                // Random point counts are almost never right, so also try the count which fits
+                // the length, and its neighbours, at the offsets used by paths and polygons.
+                for &count_offset in &[0, 1] {
+                    if len < count_offset + 4 {
+                        continue;
+                    }
+                    let count = (len - count_offset - 4) as i32 / 16;
+                    for &count in &[count - 1, count, count + 1, -count, i32::MAX, i32::MIN] {
+                        NetworkEndian::write_i32(&mut bytes[count_offset..], count);
+                        check_decode_all(&bytes);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn polygon_binary_roundtrip() {
+        let mut bytes = create_testing_output();
+        let input_polygon = PgPolygon(vec![PgPoint(4.5, 3439.1), PgPoint(-1., 0.), PgPoint(2., 2.)]);
+        ToSql::<Polygon, Pg>::to_sql(&input_polygon, &mut bytes).unwrap();
+        let output_polygon: PgPolygon =
+            FromSql::<Polygon, Pg>::from_sql(Some(bytes.as_ref())).unwrap();
+        assert_eq!(input_polygon, output_polygon);
+    }
+
+    #[test]
+    fn polygon_from_sql_rejects_wrong_lengths() {
+        let mut bytes = create_testing_output();
+        let input_polygon = PgPolygon(vec![PgPoint(4.5, 3439.1), PgPoint(-1., 0.)]);
+        ToSql::<Polygon, Pg>::to_sql(&input_polygon, &mut bytes).unwrap();
+        let bytes: &[u8] = bytes.as_ref();
+        for len in 0..bytes.len() {
+            let polygon: Result<PgPolygon, _> =
+                FromSql::<Polygon, Pg>::from_sql(Some(&bytes[..len]));
+            assert!(polygon.is_err(), "decoded a polygon from {} bytes", len);
+        }
+        let mut trailing = bytes.to_vec();
+        trailing.push(0);
+        let polygon: Result<PgPolygon, _> = FromSql::<Polygon, Pg>::from_sql(Some(&trailing));
+        assert!(polygon.is_err());
+    }
+
     #[test]
     fn no_point_from_sql() {
         let uuid: Result<PgPoint, _> = FromSql::<Point, Pg>::from_sql(None);
@@ -204,8 +754,59 @@ mod tests {
                 circles -> Nullable<Circle>,
             }
         }
+        table! {
+            use diesel::sql_types::*;
+            use sql_types::Lseg;
+            lseg_roundtrip {
+                id -> Integer,
+                segments -> Nullable<Lseg>,
+            }
+        }
+        table! {
+            use diesel::sql_types::*;
+            use sql_types::Line;
+            line_roundtrip {
+                id -> Integer,
+                lines -> Nullable<Line>,
+            }
+        }
+        table! {
+            use diesel::sql_types::*;
+            use sql_types::Path;
+            path_roundtrip {
+                id -> Integer,
+                paths -> Nullable<Path>,
+            }
+        }
+        table! {
+            use diesel::sql_types::*;
+            use sql_types::Polygon;
+            polygon_roundtrip {
+                id -> Integer,
+                polygons -> Nullable<Polygon>,
+            }
+        }
+        table! {
+            use diesel::sql_types::*;
+            use sql_types::Point;
+            point_array_roundtrip {
+                id -> Integer,
+                waypoints -> Array<Point>,
+                sparse_waypoints -> Array<Nullable<Point>>,
+            }
+        }
+        table! {
+            use diesel::sql_types::*;
+            use sql_types::Box;
+            transformed_shapes {
+                id -> Integer,
+                bounds -> Box,
+            }
+        }
     }
 
+    numeric_expr!(schema::transformed_shapes::bounds);
+
     #[test]
     fn point_is_insertable() {
         // Compile check that PgPoint can be used in insertable context,
@@ -273,6 +874,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn box_constructor_matches_postgres() {
+        let connection = connection();
+        let values = [-1., 0., 2., f64::NAN];
+        for &x1 in &values {
+            for &y1 in &values {
+                for &x2 in &values {
+                    for &y2 in &values {
+                        let (a, b) = (PgPoint(x1, y1), PgPoint(x2, y2));
+                        let canonical = PgBox::new(a, b);
+                        let constructed = select(sql::<sql_types::Box>(&format!(
+                            "box(point '({},{})', point '({},{})')",
+                            x1, y1, x2, y2
+                        ))).get_result::<PgBox>(&connection)
+                            .unwrap();
+                        let received = select(PgBox(a, b).into_sql::<sql_types::Box>())
+                            .get_result::<PgBox>(&connection);
+                        // NaN != NaN, so compare the bit patterns of the tuple as is.
+                        let bits = |PgBox(low, high): PgBox| {
+                            [low.0, low.1, high.0, high.1]
+                                .iter()
+                                .map(|c| c.to_bits())
+                                .collect::<Vec<_>>()
+                        };
+                        assert_eq!(bits(canonical), bits(constructed), "{:?}", canonical);
+                        match received {
+                            Ok(received) => {
+                                assert_eq!(bits(canonical), bits(received), "{:?}", canonical)
+                            }
+                            // The validation feature refuses to send NaN coordinates.
+                            Err(_) => assert!(
+                                cfg!(feature = "validation")
+                                    && [x1, y1, x2, y2].iter().any(|c| c.is_nan())
+                            ),
+                        }
+                        assert_eq!(bits(canonical), bits(PgBox::new(b, a)));
+                        assert_eq!(bits(canonical), bits(PgBox::new(canonical.1, canonical.0)));
+                        let (low, high) = PgBox(b, a).corners();
+                        assert_eq!(bits(canonical), bits(PgBox(low, high)));
+                        let (low, high) = (PgBox(a, b).low(), PgBox(a, b).high());
+                        assert_eq!(bits(canonical), bits(PgBox(low, high)));
+                    }
+                }
+            }
+        }
+    }
+
     use diesel::expression::AsExpression;
 
     #[test]
@@ -296,6 +944,576 @@ mod tests {
         assert!(is_contained);
     }
 
+    #[test]
+    fn contains_queries() {
+        let connection = connection();
+        let point = PgPoint(1., 1.);
+        let bounding_box = PgBox(PgPoint(0., 0.), PgPoint(2., 2.));
+        let bounding_circle = PgCircle(PgPoint(0., 0.), 3.0);
+        let triangle = PgPolygon(vec![PgPoint(0., 0.), PgPoint(4., 0.), PgPoint(0., 4.)]);
+        let route = PgPath {
+            closed: false,
+            points: vec![PgPoint(0., 0.), PgPoint(2., 2.)],
+        };
+        let contains = diesel::select((
+            bounding_box
+                .into_sql::<sql_types::Box>()
+                .contains(point.into_sql::<Point>()),
+            bounding_box
+                .into_sql::<sql_types::Box>()
+                .contains(PgBox(PgPoint(0.5, 0.5), PgPoint(1., 1.)).into_sql::<sql_types::Box>()),
+            bounding_circle
+                .into_sql::<Circle>()
+                .contains(point.into_sql::<Point>()),
+            bounding_circle
+                .into_sql::<Circle>()
+                .contains(PgCircle(PgPoint(1., 0.), 1.).into_sql::<Circle>()),
+            triangle
+                .clone()
+                .into_sql::<Polygon>()
+                .contains(point.into_sql::<Point>()),
+            route
+                .clone()
+                .into_sql::<Path>()
+                .contains(point.into_sql::<Point>()),
+        )).get_result::<(bool, bool, bool, bool, bool, bool)>(&connection);
+        assert_eq!(Ok((true, true, true, true, true, true)), contains);
+
+        let outside = PgPoint(5., 5.);
+        let contains = diesel::select((
+            bounding_box
+                .into_sql::<sql_types::Box>()
+                .contains(outside.into_sql::<Point>()),
+            bounding_circle
+                .into_sql::<Circle>()
+                .contains(outside.into_sql::<Point>()),
+            triangle
+                .clone()
+                .into_sql::<Polygon>()
+                .contains(outside.into_sql::<Point>()),
+            route.into_sql::<Path>().contains(outside.into_sql::<Point>()),
+        )).get_result::<(bool, bool, bool, bool)>(&connection);
+        assert_eq!(Ok((false, false, false, false)), contains);
+    }
+
+    #[test]
+    fn overlaps_with_queries() {
+        let connection = connection();
+        let square = PgBox(PgPoint(0., 0.), PgPoint(2., 2.));
+        let circle = PgCircle(PgPoint(0., 0.), 1.);
+        let triangle = PgPolygon(vec![PgPoint(0., 0.), PgPoint(4., 0.), PgPoint(0., 4.)]);
+        let overlaps = diesel::select((
+            square
+                .into_sql::<sql_types::Box>()
+                .overlaps_with(PgBox(PgPoint(1., 1.), PgPoint(3., 3.))),
+            square
+                .into_sql::<sql_types::Box>()
+                .overlaps_with(PgBox(PgPoint(3., 3.), PgPoint(4., 4.))),
+            circle
+                .into_sql::<Circle>()
+                .overlaps_with(PgCircle(PgPoint(1.5, 0.), 1.)),
+            circle
+                .into_sql::<Circle>()
+                .overlaps_with(PgCircle(PgPoint(5., 0.), 1.)),
+            triangle.clone().into_sql::<Polygon>().overlaps_with(PgPolygon(vec![
+                PgPoint(1., 1.),
+                PgPoint(5., 1.),
+                PgPoint(5., 5.),
+            ])),
+            triangle.into_sql::<Polygon>().overlaps_with(PgPolygon(vec![
+                PgPoint(3., 3.),
+                PgPoint(5., 3.),
+                PgPoint(5., 5.),
+            ])),
+        )).get_result::<(bool, bool, bool, bool, bool, bool)>(&connection);
+        assert_eq!(Ok((true, false, true, false, true, false)), overlaps);
+    }
+
+    #[test]
+    fn distance_queries() {
+        let connection = connection();
+        let point = PgPoint(0., 0.).into_sql::<Point>();
+        let segment = PgLineSegment(PgPoint(3., -1.), PgPoint(3., 1.)).into_sql::<Lseg>();
+        let route = PgPath {
+            closed: false,
+            points: vec![PgPoint(0., 4.), PgPoint(4., 4.)],
+        };
+        let unit_box = PgBox(PgPoint(5., 0.), PgPoint(6., 1.)).into_sql::<sql_types::Box>();
+        let triangle = PgPolygon(vec![PgPoint(0., -2.), PgPoint(1., -3.), PgPoint(-1., -3.)]);
+        let vertical = PgLine::from_points(PgPoint(-2., 0.), PgPoint(-2., 1.))
+            .unwrap()
+            .into_sql::<Line>();
+        let circle = PgCircle(PgPoint(0., 10.), 1.).into_sql::<Circle>();
+
+        let distances = select((
+            point.distance(PgPoint(3., 4.).into_sql::<Point>()),
+            point.distance(segment),
+            point.distance(route.clone().into_sql::<Path>()),
+            point.distance(unit_box),
+            point.distance(triangle.clone().into_sql::<Polygon>()),
+            point.distance(vertical),
+            point.distance(circle),
+        )).get_result::<(f64, f64, f64, f64, f64, f64, f64)>(&connection);
+        assert_eq!(Ok((5., 3., 4., 5., 2., 2., 9.)), distances);
+
+        let distances = select((
+            segment.distance(point),
+            segment.distance(PgLineSegment(PgPoint(5., -1.), PgPoint(5., 1.)).into_sql::<Lseg>()),
+            segment.distance(unit_box),
+            segment.distance(vertical),
+            route.clone().into_sql::<Path>().distance(point),
+            route.clone().into_sql::<Path>().distance(
+                PgPath {
+                    closed: false,
+                    points: vec![PgPoint(0., 6.), PgPoint(4., 6.)],
+                }.into_sql::<Path>(),
+            ),
+        )).get_result::<(f64, f64, f64, f64, f64, f64)>(&connection);
+        assert_eq!(Ok((3., 2., 2., 5., 4., 2.)), distances);
+
+        let distances = select((
+            unit_box.distance(point),
+            unit_box.distance(segment),
+            unit_box.distance(PgBox(PgPoint(8., 0.), PgPoint(9., 1.)).into_sql::<sql_types::Box>()),
+            triangle.clone().into_sql::<Polygon>().distance(point),
+            triangle
+                .clone()
+                .into_sql::<Polygon>()
+                .distance(triangle.clone().into_sql::<Polygon>()),
+            triangle.clone().into_sql::<Polygon>().distance(circle),
+        )).get_result::<(f64, f64, f64, f64, f64, f64)>(&connection);
+        let (box_point, box_lseg, box_box, polygon_point, polygon_polygon, polygon_circle) =
+            distances.unwrap();
+        assert_eq!(
+            (5., 2., 3., 2., 0.),
+            (box_point, box_lseg, box_box, polygon_point, polygon_polygon)
+        );
+        assert_eq!(11., polygon_circle);
+
+        let distances = select((
+            vertical.distance(point),
+            vertical.distance(segment),
+            vertical.distance(
+                PgLine::from_points(PgPoint(1., 0.), PgPoint(1., 1.))
+                    .unwrap()
+                    .into_sql::<Line>(),
+            ),
+            circle.distance(point),
+            circle.distance(triangle.into_sql::<Polygon>()),
+            circle.distance(PgCircle(PgPoint(0., 4.), 2.).into_sql::<Circle>()),
+        )).get_result::<(f64, f64, f64, f64, f64, f64)>(&connection);
+        assert_eq!(Ok((2., 5., 3., 9., 11., 3.)), distances);
+    }
+
+    #[test]
+    fn strict_position_queries() {
+        let connection = connection();
+        let left = PgCircle(PgPoint(0., 0.), 1.);
+        let right = PgCircle(PgPoint(3., 0.), 1.);
+        let touching = PgCircle(PgPoint(2., 0.), 1.);
+        let positions = select((
+            left.into_sql::<Circle>().is_strictly_left_of(right),
+            left.into_sql::<Circle>().is_strictly_left_of(touching),
+            right.into_sql::<Circle>().is_strictly_right_of(left),
+            left.into_sql::<Circle>().is_strictly_right_of(right),
+        )).get_result::<(bool, bool, bool, bool)>(&connection);
+        assert_eq!(Ok((true, false, true, false)), positions);
+
+        let low = PgPolygon(vec![PgPoint(0., 0.), PgPoint(1., 0.), PgPoint(0., 1.)]);
+        let high = PgPolygon(vec![PgPoint(0., 2.), PgPoint(1., 2.), PgPoint(0., 3.)]);
+        let positions = select((
+            low.clone().into_sql::<Polygon>().is_strictly_below(high.clone()),
+            high.clone().into_sql::<Polygon>().is_strictly_below(low.clone()),
+            high.clone().into_sql::<Polygon>().is_strictly_above(low.clone()),
+            low.into_sql::<Polygon>().is_strictly_above(high),
+        )).get_result::<(bool, bool, bool, bool)>(&connection);
+        assert_eq!(Ok((true, false, true, false)), positions);
+
+        let low = PgBox(PgPoint(0., 0.), PgPoint(1., 1.));
+        let touching = PgBox(PgPoint(0., 1.), PgPoint(1., 2.));
+        let positions = select((
+            low.into_sql::<sql_types::Box>().is_strictly_below(touching),
+            low.into_sql::<sql_types::Box>().is_below(touching),
+            touching.into_sql::<sql_types::Box>().is_strictly_above(low),
+            touching.into_sql::<sql_types::Box>().is_above(low),
+        )).get_result::<(bool, bool, bool, bool)>(&connection);
+        assert_eq!(Ok((false, true, false, true)), positions);
+
+        let origin = PgPoint(0., 0.);
+        let positions = select((
+            origin.into_sql::<Point>().is_strictly_left_of(PgPoint(1., -5.)),
+            origin.into_sql::<Point>().is_strictly_right_of(PgPoint(-1., 5.)),
+            origin.into_sql::<Point>().is_below(PgPoint(-1., 5.)),
+            origin.into_sql::<Point>().is_above(PgPoint(-1., 5.)),
+        )).get_result::<(bool, bool, bool, bool)>(&connection);
+        assert_eq!(Ok((true, true, true, false)), positions);
+    }
+
+    #[test]
+    fn extent_queries() {
+        let connection = connection();
+        let small = PgCircle(PgPoint(0., 0.), 1.);
+        let large = PgCircle(PgPoint(0., 0.), 2.);
+        let extents = select((
+            small.into_sql::<Circle>().does_not_extend_right_of(large),
+            large.into_sql::<Circle>().does_not_extend_right_of(small),
+            small.into_sql::<Circle>().does_not_extend_left_of(large),
+            large.into_sql::<Circle>().does_not_extend_left_of(small),
+            small.into_sql::<Circle>().does_not_extend_above(large),
+            small.into_sql::<Circle>().does_not_extend_below(large),
+        )).get_result::<(bool, bool, bool, bool, bool, bool)>(&connection);
+        assert_eq!(Ok((true, false, true, false, true, true)), extents);
+
+        let low = PgPolygon(vec![PgPoint(0., 0.), PgPoint(2., 0.), PgPoint(0., 2.)]);
+        let high = PgPolygon(vec![PgPoint(0., 1.), PgPoint(2., 1.), PgPoint(0., 3.)]);
+        let extents = select((
+            low.clone().into_sql::<Polygon>().does_not_extend_above(high.clone()),
+            high.clone().into_sql::<Polygon>().does_not_extend_above(low.clone()),
+            high.clone().into_sql::<Polygon>().does_not_extend_below(low.clone()),
+            low.into_sql::<Polygon>().does_not_extend_below(high),
+        )).get_result::<(bool, bool, bool, bool)>(&connection);
+        assert_eq!(Ok((true, false, true, false)), extents);
+    }
+
+    #[test]
+    fn intersection_queries() {
+        let connection = connection();
+        let horizontal = PgLine::from_slope_intercept(0., 1.);
+        let diagonal = PgLine::from_points(PgPoint(0., 0.), PgPoint(1., 1.)).unwrap();
+        let unit_box = PgBox(PgPoint(1., 1.), PgPoint(0., 0.));
+        let far_box = PgBox(PgPoint(5., 5.), PgPoint(4., 4.));
+        let zigzag = PgPath {
+            closed: false,
+            points: vec![PgPoint(0., 0.), PgPoint(2., 2.), PgPoint(4., 0.)],
+        };
+        let crossing = PgPath {
+            closed: false,
+            points: vec![PgPoint(0., 1.), PgPoint(4., 1.)],
+        };
+        let intersects = select((
+            horizontal.into_sql::<Line>().intersects(diagonal.into_sql::<Line>()),
+            horizontal.into_sql::<Line>().intersects(unit_box.into_sql::<sql_types::Box>()),
+            horizontal.into_sql::<Line>().intersects(far_box.into_sql::<sql_types::Box>()),
+            zigzag.into_sql::<Path>().intersects(crossing.into_sql::<Path>()),
+            PgLineSegment(PgPoint(0., 0.), PgPoint(2., 2.))
+                .into_sql::<Lseg>()
+                .intersects(horizontal.into_sql::<Line>()),
+        )).get_result::<(bool, bool, bool, bool, bool)>(&connection);
+        assert_eq!(Ok((true, true, false, true, true)), intersects);
+
+        let intersections = select((
+            horizontal.into_sql::<Line>().intersection(diagonal.into_sql::<Line>()),
+            horizontal
+                .into_sql::<Line>()
+                .intersection(PgLine::from_slope_intercept(0., 2.).into_sql::<Line>()),
+            unit_box
+                .into_sql::<sql_types::Box>()
+                .intersection(far_box.into_sql::<sql_types::Box>()),
+        )).get_result::<(Option<PgPoint>, Option<PgPoint>, Option<PgBox>)>(&connection);
+        assert_eq!(Ok((Some(PgPoint(1., 1.)), None, None)), intersections);
+    }
+
+    #[test]
+    fn closest_point_queries() {
+        let connection = connection();
+        let x_axis = PgLine::from_slope_intercept(0., 0.);
+        let unit_box = PgBox(PgPoint(0., 0.), PgPoint(1., 1.));
+        let road = PgLineSegment(PgPoint(0., 0.), PgPoint(2., 0.));
+        let parallel_road = PgLineSegment(PgPoint(0., 1.), PgPoint(1., 1.));
+        let crossing_road = PgLineSegment(PgPoint(1., -1.), PgPoint(1., 1.));
+        let closest = select((
+            PgPoint(1., 1.)
+                .into_sql::<Point>()
+                .closest_point_to(x_axis.into_sql::<Line>()),
+            PgLineSegment(PgPoint(0., 3.), PgPoint(2., 3.))
+                .into_sql::<Lseg>()
+                .closest_point_to(unit_box.into_sql::<sql_types::Box>()),
+            road.into_sql::<Lseg>()
+                .closest_point_to(parallel_road.into_sql::<Lseg>()),
+            x_axis
+                .into_sql::<Line>()
+                .closest_point_to(crossing_road.into_sql::<Lseg>()),
+        )).get_result::<(PgPoint, PgPoint, Option<PgPoint>, Option<PgPoint>)>(&connection);
+        assert_eq!(
+            Ok((PgPoint(1., 0.), PgPoint(0., 1.), None, Some(PgPoint(1., 0.)))),
+            closest
+        );
+    }
+
+    #[test]
+    fn orientation_queries() {
+        let connection = connection();
+        let rising = PgLine::from_points(PgPoint(0., 0.), PgPoint(1., 1.)).unwrap();
+        let falling = PgLine::from_points(PgPoint(0., 1.), PgPoint(1., 0.)).unwrap();
+        let x_axis = PgLine::from_slope_intercept(0., 0.);
+        let y_axis = PgLine::from_points(PgPoint(0., 0.), PgPoint(0., 1.)).unwrap();
+        let orientations = select((
+            rising.into_sql::<Line>().is_perpendicular_to(falling),
+            rising.into_sql::<Line>().is_parallel_to(falling),
+            x_axis.into_sql::<Line>().is_horizontal(),
+            y_axis.into_sql::<Line>().is_vertical(),
+            rising.into_sql::<Line>().is_horizontal(),
+            PgLineSegment(PgPoint(2., 0.), PgPoint(2., 5.))
+                .into_sql::<Lseg>()
+                .is_vertical(),
+        )).get_result::<(bool, bool, bool, bool, bool, bool)>(&connection);
+        assert_eq!(Ok((true, false, true, true, false, true)), orientations);
+
+        let alignments = select((
+            PgPoint(1., 2.)
+                .into_sql::<Point>()
+                .is_horizontal_with(PgPoint(3., 2.)),
+            PgPoint(1., 2.)
+                .into_sql::<Point>()
+                .is_vertical_with(PgPoint(3., 2.)),
+        )).get_result::<(bool, bool)>(&connection);
+        assert_eq!(Ok((true, false)), alignments);
+    }
+
+    #[test]
+    fn measurement_queries() {
+        let connection = connection();
+        let square = vec![PgPoint(0., 0.), PgPoint(2., 0.), PgPoint(2., 2.), PgPoint(0., 2.)];
+        let open_path = PgPath {
+            closed: false,
+            points: square.clone(),
+        };
+        let closed_path = PgPath {
+            closed: true,
+            points: square.clone(),
+        };
+        let measurements = select((
+            open_path.clone().into_sql::<Path>().length(),
+            closed_path.into_sql::<Path>().length(),
+            open_path.into_sql::<Path>().num_points(),
+            PgPolygon(square).into_sql::<Polygon>().center(),
+            PgLineSegment(PgPoint(0., 0.), PgPoint(2., 4.))
+                .into_sql::<Lseg>()
+                .center(),
+            PgCircle(PgPoint(3., 1.), 2.).into_sql::<Circle>().center(),
+        )).get_result::<(f64, f64, i32, PgPoint, PgPoint, PgPoint)>(&connection);
+        assert_eq!(
+            Ok((
+                6.,
+                8.,
+                4,
+                PgPoint(1., 1.),
+                PgPoint(1., 2.),
+                PgPoint(3., 1.)
+            )),
+            measurements
+        );
+    }
+
+    #[test]
+    fn measurement_functions() {
+        use dsl::{area, center, length, npoints};
+
+        let connection = connection();
+        let square = vec![PgPoint(0., 0.), PgPoint(2., 0.), PgPoint(2., 2.), PgPoint(0., 2.)];
+        let open_path = PgPath {
+            closed: false,
+            points: square.clone(),
+        };
+        let closed_path = PgPath {
+            closed: true,
+            points: square,
+        };
+        let circle = PgCircle(PgPoint(3., 1.), 1.);
+        let measurements = select((
+            area(open_path.clone().into_sql::<Path>()),
+            area(closed_path.into_sql::<Path>()),
+            area(circle.into_sql::<Circle>()),
+            center(circle.into_sql::<Circle>()),
+            length(open_path.clone().into_sql::<Path>()),
+            npoints(open_path.into_sql::<Path>()),
+        )).get_result::<(Option<f64>, Option<f64>, f64, PgPoint, f64, i32)>(&connection);
+        assert_eq!(
+            Ok((
+                None,
+                Some(4.),
+                ::std::f64::consts::PI,
+                PgPoint(3., 1.),
+                6.,
+                4
+            )),
+            measurements
+        );
+    }
+
+    #[test]
+    fn conversion_functions() {
+        use dsl::{to_box, to_circle, to_point, to_polygon};
+
+        let connection = connection();
+        let square = vec![PgPoint(0., 0.), PgPoint(0., 2.), PgPoint(2., 2.), PgPoint(2., 0.)];
+        let polygon = PgPolygon(square.clone());
+        let closed_path = PgPath {
+            closed: true,
+            points: square.clone(),
+        };
+        let circle = PgCircle(PgPoint(1., 1.), 1.);
+        let conversions = select((
+            to_box(circle.into_sql::<Circle>()),
+            to_box(polygon.clone().into_sql::<Polygon>()),
+            to_circle(polygon.clone().into_sql::<Polygon>()),
+            to_point(polygon.clone().into_sql::<Polygon>()),
+            to_point(circle.into_sql::<Circle>()),
+            to_point(PgLineSegment(PgPoint(0., 0.), PgPoint(2., 2.)).into_sql::<Lseg>()),
+            to_polygon(closed_path.into_sql::<Path>()),
+            to_polygon(circle.into_sql::<Circle>()),
+        )).get_result::<(PgBox, PgBox, PgCircle, PgPoint, PgPoint, PgPoint, PgPolygon, PgPolygon)>(
+            &connection,
+        );
+        let (circle_box, polygon_box, circle, center, circle_center, midpoint, path, dodecagon) =
+            conversions.unwrap();
+        let PgBox(PgPoint(x1, y1), PgPoint(x2, y2)) = circle_box;
+        assert!(fp_eq((x1 - x2).abs(), 2f64.sqrt()));
+        assert!(fp_eq((y1 - y2).abs(), 2f64.sqrt()));
+        assert_eq!(PgBox(PgPoint(0., 0.), PgPoint(2., 2.)), polygon_box);
+        assert_eq!(PgCircle(PgPoint(1., 1.), 2f64.sqrt()), circle);
+        assert_eq!(PgPoint(1., 1.), center);
+        assert_eq!(PgPoint(1., 1.), circle_center);
+        assert_eq!(PgPoint(1., 1.), midpoint);
+        assert_eq!(polygon, path);
+        assert_eq!(12, dodecagon.0.len());
+    }
+
+    #[test]
+    fn path_functions() {
+        use dsl::{isclosed, isopen, pclose, popen};
+
+        let connection = connection();
+        let trace = PgPath {
+            closed: false,
+            points: vec![PgPoint(0., 0.), PgPoint(1., 1.), PgPoint(2., 0.)],
+        };
+        let states = select((
+            isclosed(pclose(trace.clone().into_sql::<Path>())),
+            isopen(pclose(trace.clone().into_sql::<Path>())),
+            isopen(popen(pclose(trace.into_sql::<Path>()))),
+        )).get_result::<(bool, bool, bool)>(&connection);
+        assert_eq!(Ok((true, false, true)), states);
+    }
+
+    #[test]
+    fn approx_eq_agrees_with_same_as() {
+        let connection = connection();
+        let offsets = [0., 0.5E-06, 0.9E-06, 1.1E-06, 1.0E-03, f64::NAN];
+        let origin = PgPoint(1., 2.);
+        for &dx in &offsets {
+            for &dy in &offsets {
+                if cfg!(feature = "validation") && (dx.is_nan() || dy.is_nan()) {
+                    // The validation feature refuses to send NaN coordinates.
+                    continue;
+                }
+                let point = PgPoint(1. + dx, 2. + dy);
+                let same = select(point.into_sql::<Point>().same_as(origin))
+                    .get_result::<bool>(&connection);
+                assert_eq!(Ok(origin.approx_eq(&point)), same, "{:?}", point);
+
+                let corners = PgBox(PgPoint(-1., -1.), PgPoint(1., 1.));
+                // Swapped corners are reordered by Postgres before comparing.
+                let other = PgBox(PgPoint(1. + dx, -1.), PgPoint(-1., 1. + dy));
+                let same = select(corners.into_sql::<sql_types::Box>().same_as(other))
+                    .get_result::<bool>(&connection);
+                assert_eq!(Ok(corners.approx_eq(&other)), same, "{:?}", other);
+
+                let circle = PgCircle(origin, 1.);
+                let other = PgCircle(PgPoint(1. + dx, 2.), 1. + dy);
+                let same = select(circle.into_sql::<Circle>().same_as(other))
+                    .get_result::<bool>(&connection);
+                assert_eq!(Ok(circle.approx_eq(&other)), same, "{:?}", other);
+            }
+        }
+    }
+
+    #[test]
+    fn transform_queries() {
+        let connection = connection();
+        let path = PgPath {
+            closed: false,
+            points: vec![PgPoint(0., 0.), PgPoint(1., 2.)],
+        };
+        let transformed = select((
+            path.clone().into_sql::<Path>().plus(PgPoint(1., 1.)),
+            path.clone().into_sql::<Path>().minus(PgPoint(1., 1.)),
+            path.clone().into_sql::<Path>().times(PgPoint(2., 0.)),
+            path.into_sql::<Path>().divided_by(PgPoint(2., 0.)),
+        )).get_result::<(PgPath, PgPath, PgPath, PgPath)>(&connection);
+        let open_path = |points| PgPath {
+            closed: false,
+            points,
+        };
+        assert_eq!(
+            Ok((
+                open_path(vec![PgPoint(1., 1.), PgPoint(2., 3.)]),
+                open_path(vec![PgPoint(-1., -1.), PgPoint(0., 1.)]),
+                open_path(vec![PgPoint(0., 0.), PgPoint(2., 4.)]),
+                open_path(vec![PgPoint(0., 0.), PgPoint(0.5, 1.)]),
+            )),
+            transformed
+        );
+
+        let distance = select(
+            PgPoint(0., 0.)
+                .into_sql::<Point>()
+                .plus(PgPoint(3., 0.))
+                .distance(PgPoint(0., 4.).into_sql::<Point>()),
+        ).get_result::<f64>(&connection);
+        assert_eq!(Ok(5.), distance);
+    }
+
+    #[test]
+    fn transform_operators() {
+        use self::schema::transformed_shapes::dsl::*;
+        use dsl::center;
+
+        let connection = connection();
+        let point = PgPoint(2., 0.).into_sql::<Point>();
+        let circle = PgCircle(PgPoint(1., 2.), 3.).into_sql::<Circle>();
+        let transformed = select((
+            point + PgPoint(1., 1.),
+            point - PgPoint(1., 1.),
+            point * PgPoint(0., 1.),
+            point / PgPoint(2., 0.),
+            center(circle) + PgPoint(1., 1.),
+            point.plus(PgPoint(1., 0.)) * PgPoint(2., 0.),
+        )).get_result(&connection);
+        let expected = (
+            PgPoint(3., 1.),
+            PgPoint(1., -1.),
+            PgPoint(0., 2.),
+            PgPoint(1., 0.),
+            PgPoint(2., 3.),
+            PgPoint(6., 0.),
+        );
+        assert_eq!(Ok(expected), transformed);
+
+        connection
+            .execute(
+                "CREATE TABLE transformed_shapes (
+            id SERIAL PRIMARY KEY,
+            bounds BOX NOT NULL
+        )",
+            ).unwrap();
+        connection
+            .execute("INSERT INTO transformed_shapes (bounds) VALUES (box '((0,0),(2,2))')")
+            .unwrap();
+        diesel::update(transformed_shapes)
+            .set(bounds.eq((bounds - PgPoint(1., 1.)) * PgPoint(2., 0.)))
+            .execute(&connection)
+            .unwrap();
+        let moved = transformed_shapes
+            .select((bounds, bounds / PgPoint(2., 0.) + PgPoint(1., 1.)))
+            .get_result(&connection);
+        let expected = (
+            PgBox(PgPoint(-2., -2.), PgPoint(2., 2.)),
+            PgBox(PgPoint(0., 0.), PgPoint(2., 2.)),
+        );
+        assert_eq!(Ok(expected), moved);
+    }
+
     #[test]
     fn circle_roundtrip() {
         let connection = connection();
@@ -327,4 +1545,348 @@ mod tests {
             Err(_) => panic!(),
         }
     }
+
+    #[test]
+    fn lseg_roundtrip() {
+        let connection = connection();
+        connection
+            .execute(
+                "CREATE TABLE lseg_roundtrip (
+            id SERIAL PRIMARY KEY,
+            segments LSEG
+        )",
+            ).unwrap();
+        use self::schema::lseg_roundtrip;
+        #[derive(Debug, PartialEq, Insertable, Queryable)]
+        #[table_name = "lseg_roundtrip"]
+        struct Roundtrip {
+            id: i32,
+            segments: Option<::pg::types::geometric::PgLineSegment>,
+        }
+        let data = Roundtrip {
+            id: 6,
+            segments: Some(PgLineSegment(PgPoint(3., 4.), PgPoint(-1., 0.5))),
+        };
+        diesel::insert_into(lseg_roundtrip::table)
+            .values(&data)
+            .execute(&connection)
+            .unwrap();
+        let x = lseg_roundtrip::table.first::<Roundtrip>(&connection);
+        match x {
+            Ok(record) => assert_eq!(data, record),
+            Err(_) => panic!(),
+        }
+    }
+
+    #[test]
+    fn line_roundtrip() {
+        let connection = connection();
+        connection
+            .execute(
+                "CREATE TABLE line_roundtrip (
+            id SERIAL PRIMARY KEY,
+            lines LINE
+        )",
+            ).unwrap();
+        use self::schema::line_roundtrip;
+        #[derive(Debug, PartialEq, Insertable, Queryable)]
+        #[table_name = "line_roundtrip"]
+        struct Roundtrip {
+            id: i32,
+            lines: Option<::pg::types::geometric::PgLine>,
+        }
+        let data = Roundtrip {
+            id: 6,
+            lines: Some(PgLine {
+                a: 2.,
+                b: -1.,
+                c: 0.5,
+            }),
+        };
+        diesel::insert_into(line_roundtrip::table)
+            .values(&data)
+            .execute(&connection)
+            .unwrap();
+        let x = line_roundtrip::table.first::<Roundtrip>(&connection);
+        match x {
+            Ok(record) => assert_eq!(data, record),
+            Err(_) => panic!(),
+        }
+    }
+
+    #[test]
+    fn line_constructors_match_postgres() {
+        let connection = connection();
+        let cases = [
+            (PgPoint(1., 2.), PgPoint(3., 6.)),
+            (PgPoint(1., 2.), PgPoint(1., 6.)),
+            (PgPoint(1., 2.), PgPoint(3., 2.)),
+            (PgPoint(-4., 0.5), PgPoint(2., -7.25)),
+            (PgPoint(0., 0.), PgPoint(1., 1.)),
+        ];
+        for &(p1, p2) in cases.iter() {
+            let expected = select(sql::<Line>(&format!(
+                "line(point '({}, {})', point '({}, {})')",
+                p1.0, p1.1, p2.0, p2.1
+            ))).get_result::<PgLine>(&connection)
+            .unwrap();
+            assert_eq!(Some(expected), PgLine::from_points(p1, p2));
+        }
+
+        // Postgres rejects points which are the same within its tolerance.
+        let same = [
+            (PgPoint(1., 2.), PgPoint(1., 2.)),
+            (PgPoint(1., 2.), PgPoint(1. + 0.5E-06, 2. - 0.5E-06)),
+        ];
+        for &(p1, p2) in same.iter() {
+            let result = connection.transaction(|| {
+                select(sql::<Line>(&format!(
+                    "line(point '({}, {})', point '({}, {})')",
+                    p1.0, p1.1, p2.0, p2.1
+                ))).get_result::<PgLine>(&connection)
+            });
+            assert!(result.is_err());
+            assert_eq!(None, PgLine::from_points(p1, p2));
+        }
+
+        let expected = select(sql::<Line>("line '{2, -1, 3}'"))
+            .get_result::<PgLine>(&connection)
+            .unwrap();
+        assert_eq!(expected, PgLine::from_slope_intercept(2., 3.));
+        let expected = select(sql::<Line>("line '{0, -1, 3}'"))
+            .get_result::<PgLine>(&connection)
+            .unwrap();
+        assert_eq!(expected, PgLine::from_slope_intercept(0., 3.));
+    }
+
+    #[test]
+    fn path_roundtrip() {
+        let connection = connection();
+        connection
+            .execute(
+                "CREATE TABLE path_roundtrip (
+            id SERIAL PRIMARY KEY,
+            paths PATH
+        )",
+            ).unwrap();
+        use self::schema::path_roundtrip;
+        #[derive(Debug, PartialEq, Insertable, Queryable)]
+        #[table_name = "path_roundtrip"]
+        struct Roundtrip {
+            id: i32,
+            paths: Option<::pg::types::geometric::PgPath>,
+        }
+        let data = vec![
+            Roundtrip {
+                id: 6,
+                paths: Some(PgPath {
+                    closed: false,
+                    points: vec![PgPoint(3., 4.), PgPoint(-1., 0.5)],
+                }),
+            },
+            Roundtrip {
+                id: 7,
+                paths: Some(PgPath {
+                    closed: true,
+                    points: vec![PgPoint(0., 0.), PgPoint(1., 0.), PgPoint(1., 1.)],
+                }),
+            },
+        ];
+        diesel::insert_into(path_roundtrip::table)
+            .values(&data)
+            .execute(&connection)
+            .unwrap();
+        let x = path_roundtrip::table
+            .order(path_roundtrip::id)
+            .load::<Roundtrip>(&connection);
+        match x {
+            Ok(records) => assert_eq!(data, records),
+            Err(_) => panic!(),
+        }
+        let is_closed = select(sql::<diesel::sql_types::Bool>(
+            "isclosed(paths) FROM path_roundtrip WHERE id = 7",
+        )).get_result::<bool>(&connection);
+        assert_eq!(Ok(true), is_closed);
+    }
+
+    #[test]
+    fn polygon_roundtrip() {
+        let connection = connection();
+        connection
+            .execute(
+                "CREATE TABLE polygon_roundtrip (
+            id SERIAL PRIMARY KEY,
+            polygons POLYGON
+        )",
+            ).unwrap();
+        use self::schema::polygon_roundtrip;
+        #[derive(Debug, PartialEq, Insertable, Queryable)]
+        #[table_name = "polygon_roundtrip"]
+        struct Roundtrip {
+            id: i32,
+            polygons: Option<::pg::types::geometric::PgPolygon>,
+        }
+        let data = Roundtrip {
+            id: 6,
+            polygons: Some(PgPolygon(vec![
+                PgPoint(0., 0.),
+                PgPoint(4., 0.),
+                PgPoint(4., 3.),
+                PgPoint(0., 3.),
+            ])),
+        };
+        diesel::insert_into(polygon_roundtrip::table)
+            .values(&data)
+            .execute(&connection)
+            .unwrap();
+        let x = polygon_roundtrip::table.first::<Roundtrip>(&connection);
+        match x {
+            Ok(record) => assert_eq!(data, record),
+            Err(_) => panic!(),
+        }
+        // The server must have rebuilt the bounding box that is not sent on the wire.
+        let bounding_box = select(sql::<sql_types::Box>(
+            "box(polygons) FROM polygon_roundtrip WHERE id = 6",
+        )).get_result::<PgBox>(&connection);
+        assert_eq!(Ok(PgBox(PgPoint(0., 0.), PgPoint(4., 3.))), bounding_box);
+    }
+
+    #[test]
+    fn polygon_operator_queries() {
+        let connection = connection();
+        let square = PgPolygon(vec![
+            PgPoint(0., 0.),
+            PgPoint(2., 0.),
+            PgPoint(2., 2.),
+            PgPoint(0., 2.),
+        ]);
+        let inner = PgPolygon(vec![PgPoint(0.5, 0.5), PgPoint(1.5, 0.5), PgPoint(1., 1.5)]);
+        let is_same = diesel::select(
+            square
+                .clone()
+                .into_sql::<Polygon>()
+                .same_as(square.clone()),
+        ).get_result::<bool>(&connection);
+        assert_eq!(Ok(true), is_same);
+        let is_same = diesel::select(inner.clone().into_sql::<Polygon>().same_as(square.clone()))
+            .get_result::<bool>(&connection);
+        assert_eq!(Ok(false), is_same);
+        let is_contained = diesel::select(
+            inner
+                .clone()
+                .into_sql::<Polygon>()
+                .is_contained_by(square.clone().into_sql::<Polygon>()),
+        ).get_result::<bool>(&connection);
+        assert_eq!(Ok(true), is_contained);
+        let is_contained = diesel::select(
+            PgPoint(3., 1.)
+                .into_sql::<Point>()
+                .is_contained_by(square.clone().into_sql::<Polygon>()),
+        ).get_result::<bool>(&connection);
+        assert_eq!(Ok(false), is_contained);
+    }
+
+    #[test]
+    fn point_array_roundtrip() {
+        let connection = connection();
+        connection
+            .execute(
+                "CREATE TABLE point_array_roundtrip (
+            id SERIAL PRIMARY KEY,
+            waypoints POINT[] NOT NULL,
+            sparse_waypoints POINT[] NOT NULL
+        )",
+            ).unwrap();
+        use self::schema::point_array_roundtrip;
+        #[derive(Debug, PartialEq, Insertable, Queryable)]
+        #[table_name = "point_array_roundtrip"]
+        struct Roundtrip {
+            id: i32,
+            waypoints: Vec<PgPoint>,
+            sparse_waypoints: Vec<Option<PgPoint>>,
+        }
+        let data = vec![
+            Roundtrip {
+                id: 6,
+                waypoints: vec![PgPoint(3., 4.), PgPoint(-1., 0.5)],
+                sparse_waypoints: vec![Some(PgPoint(1., 2.)), None, Some(PgPoint(0., 0.))],
+            },
+            Roundtrip {
+                id: 7,
+                waypoints: vec![],
+                sparse_waypoints: vec![None],
+            },
+        ];
+        diesel::insert_into(point_array_roundtrip::table)
+            .values(&data)
+            .execute(&connection)
+            .unwrap();
+        let x = point_array_roundtrip::table
+            .order(point_array_roundtrip::id)
+            .load::<Roundtrip>(&connection);
+        match x {
+            Ok(records) => assert_eq!(data, records),
+            Err(_) => panic!(),
+        }
+        let third_waypoint = select(sql::<Point>(
+            "sparse_waypoints[3] FROM point_array_roundtrip WHERE id = 6",
+        )).get_result::<PgPoint>(&connection);
+        assert_eq!(Ok(PgPoint(0., 0.)), third_waypoint);
+    }
+
+    #[test]
+    fn box_and_circle_arrays_roundtrip() {
+        use diesel::sql_types::{Array, Nullable};
+        let connection = connection();
+        let boxes = vec![
+            PgBox(PgPoint(0., 0.), PgPoint(3., 4.)),
+            PgBox(PgPoint(-2., -1.), PgPoint(0.5, 0.)),
+        ];
+        let output = select(boxes.clone().into_sql::<Array<sql_types::Box>>())
+            .get_result::<Vec<PgBox>>(&connection);
+        assert_eq!(Ok(boxes), output);
+        let circles = vec![Some(PgCircle(PgPoint(3., 4.), 1.5)), None];
+        let output = select(circles.clone().into_sql::<Array<Nullable<Circle>>>())
+            .get_result::<Vec<Option<PgCircle>>>(&connection);
+        assert_eq!(Ok(circles), output);
+        let element_type = select(sql::<diesel::sql_types::Text>("pg_typeof(")
+            .bind::<Array<Circle>, _>(vec![PgCircle(PgPoint(0., 0.), 1.)])
+            .sql(")::text"))
+        .get_result::<String>(&connection);
+        assert_eq!(Ok("circle[]".to_string()), element_type);
+    }
+
+    #[test]
+    fn point_array_contained_queries() {
+        use diesel::sql_types::Array;
+        let connection = connection();
+        let waypoints = vec![PgPoint(1., 1.), PgPoint(5., 5.)];
+        let inside = PgBox(PgPoint(0., 0.), PgPoint(6., 6.));
+        let partial = PgBox(PgPoint(0., 0.), PgPoint(2., 2.));
+        let outside = PgBox(PgPoint(10., 10.), PgPoint(12., 12.));
+        let query = |bounding_box: PgBox| {
+            select((
+                waypoints
+                    .clone()
+                    .into_sql::<Array<Point>>()
+                    .any_is_contained_by(bounding_box.into_sql::<sql_types::Box>()),
+                waypoints
+                    .clone()
+                    .into_sql::<Array<Point>>()
+                    .all_are_contained_by(bounding_box.into_sql::<sql_types::Box>()),
+            )).get_result::<(bool, bool)>(&connection)
+        };
+        assert_eq!(Ok((true, true)), query(inside));
+        assert_eq!(Ok((true, false)), query(partial));
+        assert_eq!(Ok((false, false)), query(outside));
+
+        let polygon = PgPolygon(vec![PgPoint(0., 0.), PgPoint(4., 0.), PgPoint(0., 4.)]);
+        let any_in_polygon = select(
+            waypoints
+                .clone()
+                .into_sql::<Array<Point>>()
+                .any_is_contained_by(polygon.into_sql::<Polygon>()),
+        ).get_result::<bool>(&connection);
+        assert_eq!(Ok(true), any_in_polygon);
+    }
 }
@@ -1,6 +1,9 @@
 //! PostgreSQL specific types
 
+mod geo_ops;
 pub mod geometric;
+pub mod text;
+pub mod validation;
 
 pub mod sql_types {
     /// The PostgreSQL [Point](https://www.postgresql.org/docs/current/static/datatype-geometric.html) type.
@@ -165,4 +168,260 @@ pub mod sql_types {
     #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
     #[postgres(oid = "718", array_oid = "719")]
     pub struct Circle;
+
+    /// The PostgreSQL [Lseg](https://www.postgresql.org/docs/current/static/datatype-geometric.html) type.
+    ///
+    /// ### [`ToSql`](::diesel::serialize::ToSql) impls
+    ///
+    /// - [`PgLineSegment`](::pg::data_types::PgLineSegment)
+    ///
+    /// ### [`FromSql`](::diesel::deserialize::FromSql) impls
+    ///
+    /// - [`PgLineSegment`](::pg::data_types::PgLineSegment)
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::PgPoint;
+    /// use diesel_geometry::data_types::PgLineSegment;
+    ///
+    ///
+    /// table! {
+    ///     use diesel::sql_types::*;
+    ///     use diesel_geometry::sql_types::Lseg;
+    ///     walls {
+    ///         id -> Integer,
+    ///         name -> VarChar,
+    ///         edge -> Lseg,
+    ///     }
+    /// }
+    ///
+    /// # fn main() {
+    /// #     use diesel::insert_into;
+    /// #     use walls::dsl::*;
+    /// #     let connection = connection_no_data();
+    /// #     connection.execute("CREATE TABLE walls (
+    /// #         id SERIAL PRIMARY KEY,
+    /// #         name VARCHAR NOT NULL,
+    /// #         edge LSEG NOT NULL
+    /// #     )").unwrap();
+    /// let segment = PgLineSegment(PgPoint(0.0, 0.0), PgPoint(3.0, 4.0));
+    /// let inserted_edge = insert_into(walls)
+    ///     .values((name.eq("North Wall"), edge.eq(segment)))
+    ///     .returning(edge)
+    ///     .get_result(&connection);
+    /// assert_eq!(Ok(segment), inserted_edge);
+    /// # }
+    /// ```
+    #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+    #[postgres(oid = "601", array_oid = "1018")]
+    pub struct Lseg;
+
+    /// The PostgreSQL [Line](https://www.postgresql.org/docs/current/static/datatype-geometric.html) type.
+    ///
+    /// ### [`ToSql`](::diesel::serialize::ToSql) impls
+    ///
+    /// - [`PgLine`](::pg::data_types::PgLine)
+    ///
+    /// ### [`FromSql`](::diesel::deserialize::FromSql) impls
+    ///
+    /// - [`PgLine`](::pg::data_types::PgLine)
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::PgPoint;
+    /// use diesel_geometry::data_types::PgLine;
+    ///
+    ///
+    /// table! {
+    ///     use diesel::sql_types::*;
+    ///     use diesel_geometry::sql_types::Line;
+    ///     regressions {
+    ///         id -> Integer,
+    ///         name -> VarChar,
+    ///         fit -> Line,
+    ///     }
+    /// }
+    ///
+    /// # fn main() {
+    /// #     use diesel::insert_into;
+    /// #     use regressions::dsl::*;
+    /// #     let connection = connection_no_data();
+    /// #     connection.execute("CREATE TABLE regressions (
+    /// #         id SERIAL PRIMARY KEY,
+    /// #         name VARCHAR NOT NULL,
+    /// #         fit LINE NOT NULL
+    /// #     )").unwrap();
+    /// let line = PgLine::from_points(PgPoint(0.0, 1.0), PgPoint(2.0, 5.0)).unwrap();
+    /// let inserted_fit = insert_into(regressions)
+    ///     .values((name.eq("Rainfall"), fit.eq(line)))
+    ///     .returning(fit)
+    ///     .get_result(&connection);
+    /// assert_eq!(Ok(PgLine { a: 2.0, b: -1.0, c: 1.0 }), inserted_fit);
+    /// # }
+    /// ```
+    #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+    #[postgres(oid = "628", array_oid = "629")]
+    pub struct Line;
+
+    /// The PostgreSQL [Path](https://www.postgresql.org/docs/current/static/datatype-geometric.html) type.
+    ///
+    /// ### [`ToSql`](::diesel::serialize::ToSql) impls
+    ///
+    /// - [`PgPath`](::pg::data_types::PgPath)
+    ///
+    /// ### [`FromSql`](::diesel::deserialize::FromSql) impls
+    ///
+    /// - [`PgPath`](::pg::data_types::PgPath)
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::PgPoint;
+    /// use diesel_geometry::data_types::PgPath;
+    ///
+    ///
+    /// table! {
+    ///     use diesel::sql_types::*;
+    ///     use diesel_geometry::sql_types::Path;
+    ///     traces {
+    ///         id -> Integer,
+    ///         name -> VarChar,
+    ///         route -> Path,
+    ///     }
+    /// }
+    ///
+    /// # fn main() {
+    /// #     use diesel::insert_into;
+    /// #     use traces::dsl::*;
+    /// #     let connection = connection_no_data();
+    /// #     connection.execute("CREATE TABLE traces (
+    /// #         id SERIAL PRIMARY KEY,
+    /// #         name VARCHAR NOT NULL,
+    /// #         route PATH NOT NULL
+    /// #     )").unwrap();
+    /// let trace = PgPath {
+    ///     closed: false,
+    ///     points: vec![PgPoint(0.0, 0.0), PgPoint(1.5, 2.0), PgPoint(3.0, 2.5)],
+    /// };
+    /// let inserted_route = insert_into(traces)
+    ///     .values((name.eq("Morning Run"), route.eq(&trace)))
+    ///     .returning(route)
+    ///     .get_result(&connection);
+    /// assert_eq!(Ok(trace), inserted_route);
+    /// # }
+    /// ```
+    #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+    #[postgres(oid = "602", array_oid = "1019")]
+    pub struct Path;
+
+    /// The PostgreSQL [Polygon](https://www.postgresql.org/docs/current/static/datatype-geometric.html) type.
+    ///
+    /// ### [`ToSql`](::diesel::serialize::ToSql) impls
+    ///
+    /// - [`PgPolygon`](::pg::data_types::PgPolygon)
+    ///
+    /// ### [`FromSql`](::diesel::deserialize::FromSql) impls
+    ///
+    /// - [`PgPolygon`](::pg::data_types::PgPolygon)
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #![allow(dead_code)]
+    /// # #[macro_use] extern crate diesel;
+    /// # extern crate diesel_geometry;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel_geometry::data_types::PgPoint;
+    /// use diesel_geometry::data_types::PgPolygon;
+    /// use diesel_geometry::sql_types;
+    ///
+    ///
+    /// table! {
+    ///     use diesel::sql_types::*;
+    ///     use diesel_geometry::sql_types::Polygon;
+    ///     zones {
+    ///         id -> Integer,
+    ///         name -> VarChar,
+    ///         area -> Polygon,
+    ///     }
+    /// }
+    ///
+    /// # fn main() {
+    /// #     use diesel::insert_into;
+    /// #     use diesel_geometry::prelude::*;
+    /// #     use zones::dsl::*;
+    /// #     let connection = connection_no_data();
+    /// #     connection.execute("CREATE TABLE zones (
+    /// #         id SERIAL PRIMARY KEY,
+    /// #         name VARCHAR NOT NULL,
+    /// #         area POLYGON NOT NULL
+    /// #     )").unwrap();
+    /// let triangle = PgPolygon(vec![PgPoint(0.0, 0.0), PgPoint(4.0, 0.0), PgPoint(0.0, 3.0)]);
+    /// insert_into(zones)
+    ///     .values((name.eq("Downtown"), area.eq(&triangle)))
+    ///     .execute(&connection)
+    ///     .unwrap();
+    /// let zone_name = zones
+    ///     .select(name)
+    ///     .filter(PgPoint(1.0, 1.0).into_sql::<sql_types::Point>().is_contained_by(area))
+    ///     .first(&connection);
+    /// assert_eq!(Ok("Downtown".to_string()), zone_name);
+    /// # }
+    /// ```
+    #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+    #[postgres(oid = "604", array_oid = "1027")]
+    pub struct Polygon;
+}
+
+// Postgres translates a geometric value with `+` and `-`, and scales and rotates it with `*` and
+// `/`, always taking a point as the right hand side. Polygons have none of these operators.
+mod ops {
+    use super::sql_types::{self, Circle, Path, Point};
+    use diesel::sql_types::ops::{Add, Div, Mul, Sub};
+
+    macro_rules! point_ops {
+        ($($sql_type:ty),*) => {
+            $(
+                impl Add for $sql_type {
+                    type Rhs = Point;
+                    type Output = $sql_type;
+                }
+
+                impl Sub for $sql_type {
+                    type Rhs = Point;
+                    type Output = $sql_type;
+                }
+
+                impl Mul for $sql_type {
+                    type Rhs = Point;
+                    type Output = $sql_type;
+                }
+
+                impl Div for $sql_type {
+                    type Rhs = Point;
+                    type Output = $sql_type;
+                }
+            )*
+        };
+    }
+
+    point_ops!(Point, sql_types::Box, Circle, Path);
 }
@@ -0,0 +1,797 @@
+//! The text representation of the geometric types, as accepted and printed by Postgres.
+//!
+//! Parsing is a port of the input functions in geo_ops.c, so every bracket variant Postgres
+//! accepts is accepted here, and anything Postgres rejects is an error. The one exception is
+//! numbers in hexadecimal notation, which are not accepted. Formatting produces the same text as
+//! the Postgres output functions.
+//!
+//! https://github.com/postgres/postgres/blob/REL_15_STABLE/src/backend/utils/adt/geo_ops.c
+//!
+//! ```rust
+//! # extern crate diesel_geometry;
+//! # use diesel_geometry::data_types::{PgCircle, PgPoint};
+//! # fn main() {
+//! let circle: PgCircle = "<(1, 2), 3>".parse().unwrap();
+//! assert_eq!(PgCircle(PgPoint(1.0, 2.0), 3.0), circle);
+//! assert_eq!("<(1,2),3>", circle.to_string());
+//! # }
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::str::{self, FromStr};
+
+use diesel::deserialize::{self, FromSql};
+use diesel::pg::Pg;
+use diesel::sql_types::Text;
+
+use super::geo_ops::fp_zero;
+use super::geometric::{PgBox, PgCircle, PgLine, PgLineSegment, PgPath, PgPoint, PgPolygon};
+
+/// The error returned when a string is not the text representation of a geometric type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeometryParseError {
+    /// The name of the SQL type being parsed.
+    pub type_name: &'static str,
+    /// The string which failed to parse.
+    pub input: String,
+}
+
+impl fmt::Display for GeometryParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "invalid input syntax for type {}: \"{}\"",
+            self.type_name, self.input
+        )
+    }
+}
+
+impl Error for GeometryParseError {}
+
+// The characters `isspace` accepts in the C locale.
+fn is_space(c: u8) -> bool {
+    c == b' ' || (b'\t'..=b'\r').contains(&c)
+}
+
+// Port of `pair_count`: the number of points in a list of coordinates, which must be separated by
+// an odd number of commas.
+fn pair_count(input: &str) -> Option<usize> {
+    let commas = input.bytes().filter(|&c| c == b',').count();
+    if commas % 2 == 1 {
+        Some(commas / 2 + 1)
+    } else {
+        None
+    }
+}
+
+struct Parser<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.pos).cloned()
+    }
+
+    fn eat(&mut self, c: u8) -> bool {
+        let found = self.peek() == Some(c);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    // The position of the first character at or after `pos` which isn't a space.
+    fn after_spaces(&self, mut pos: usize) -> usize {
+        while self.input.get(pos).map(|&c| is_space(c)) == Some(true) {
+            pos += 1;
+        }
+        pos
+    }
+
+    fn skip_spaces(&mut self) {
+        self.pos = self.after_spaces(self.pos);
+    }
+
+    fn skip_digits(&mut self) -> usize {
+        let start = self.pos;
+        while self.peek().map(|c| c.is_ascii_digit()) == Some(true) {
+            self.pos += 1;
+        }
+        self.pos - start
+    }
+
+    fn at_end(&self) -> bool {
+        self.pos == self.input.len()
+    }
+
+    // Whether the `(` at the current position is the last one in the input, like the
+    // `strrchr(str, LDELIM) == str` checks in geo_ops.c.
+    fn at_last_paren(&self) -> bool {
+        self.peek() == Some(b'(') && !self.input[self.pos + 1..].contains(&b'(')
+    }
+
+    // Port of `single_decode`, which parses a number the same as `float8in`.
+    fn single(&mut self) -> Option<f64> {
+        const SPECIAL: [(&str, f64); 9] = [
+            ("nan", f64::NAN),
+            ("+nan", f64::NAN),
+            ("-nan", f64::NAN),
+            ("infinity", f64::INFINITY),
+            ("+infinity", f64::INFINITY),
+            ("-infinity", f64::NEG_INFINITY),
+            ("inf", f64::INFINITY),
+            ("+inf", f64::INFINITY),
+            ("-inf", f64::NEG_INFINITY),
+        ];
+        self.skip_spaces();
+        let rest = &self.input[self.pos..];
+        let special = SPECIAL.iter().find(|&&(name, _)| {
+            rest.len() >= name.len() && rest[..name.len()].eq_ignore_ascii_case(name.as_bytes())
+        });
+        let value = match special {
+            Some(&(name, value)) => {
+                self.pos += name.len();
+                value
+            }
+            None => self.decimal()?,
+        };
+        self.skip_spaces();
+        Some(value)
+    }
+
+    fn decimal(&mut self) -> Option<f64> {
+        let start = self.pos;
+        let _ = self.eat(b'+') || self.eat(b'-');
+        let mut digits = self.skip_digits();
+        if self.eat(b'.') {
+            digits += self.skip_digits();
+        }
+        if digits == 0 {
+            return None;
+        }
+        let mantissa_end = self.pos;
+        // The exponent is only part of the number if it has digits.
+        if self.eat(b'e') || self.eat(b'E') {
+            let _ = self.eat(b'+') || self.eat(b'-');
+            if self.skip_digits() == 0 {
+                self.pos = mantissa_end;
+            }
+        }
+        let value = str::from_utf8(&self.input[start..self.pos])
+            .ok()?
+            .parse::<f64>()
+            .ok()?;
+        // Postgres rejects numbers which overflow to infinity or underflow to zero.
+        let non_zero = self.input[start..mantissa_end]
+            .iter()
+            .any(|c| (b'1'..=b'9').contains(c));
+        if value.is_infinite() || (value == 0.0 && non_zero) {
+            return None;
+        }
+        Some(value)
+    }
+
+    // Port of `pair_decode`.
+    fn pair(&mut self) -> Option<PgPoint> {
+        self.skip_spaces();
+        let has_delim = self.eat(b'(');
+        let x = self.single()?;
+        if !self.eat(b',') {
+            return None;
+        }
+        let y = self.single()?;
+        if has_delim {
+            if !self.eat(b')') {
+                return None;
+            }
+            self.skip_spaces();
+        }
+        Some(PgPoint(x, y))
+    }
+
+    // Port of `path_decode`, returning the points and whether they were enclosed in `[ ]`.
+    fn points(&mut self, open_type: bool, num_points: usize) -> Option<(Vec<PgPoint>, bool)> {
+        self.skip_spaces();
+        let mut depth = 0;
+        let is_open = self.peek() == Some(b'[');
+        if is_open {
+            if !open_type {
+                return None;
+            }
+            depth += 1;
+            self.pos += 1;
+        } else if self.peek() == Some(b'(') {
+            let cp = self.after_spaces(self.pos + 1);
+            if self.input.get(cp) == Some(&b'(') || self.at_last_paren() {
+                depth += 1;
+                self.pos = cp;
+            }
+        }
+        let mut points = Vec::with_capacity(num_points);
+        for _ in 0..num_points {
+            points.push(self.pair()?);
+            self.eat(b',');
+        }
+        while depth > 0 {
+            if self.eat(b')') || (is_open && depth == 1 && self.eat(b']')) {
+                depth -= 1;
+                self.skip_spaces();
+            } else {
+                return None;
+            }
+        }
+        Some((points, is_open))
+    }
+}
+
+fn parse<T, F>(type_name: &'static str, input: &str, parse: F) -> Result<T, GeometryParseError>
+where
+    F: FnOnce(&mut Parser) -> Option<T>,
+{
+    let mut parser = Parser {
+        input: input.as_bytes(),
+        pos: 0,
+    };
+    match parse(&mut parser) {
+        Some(value) if parser.at_end() => Ok(value),
+        _ => Err(GeometryParseError {
+            type_name,
+            input: input.to_owned(),
+        }),
+    }
+}
+
+/// Accepts `(x,y)` or `x,y`.
+impl FromStr for PgPoint {
+    type Err = GeometryParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse("point", s, |p| p.pair())
+    }
+}
+
+/// Accepts `((x1,y1),(x2,y2))`, `(x1,y1),(x2,y2)`, `(x1,y1,x2,y2)` or `x1,y1,x2,y2`, with the
+/// corners in any order.
+impl FromStr for PgBox {
+    type Err = GeometryParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse("box", s, |p| {
+            let (points, _) = p.points(false, 2)?;
+            Some(PgBox::new(points[0], points[1]))
+        })
+    }
+}
+
+/// Accepts `<(x,y),r>`, `((x,y),r)`, `(x,y),r` or `x,y,r`.
+impl FromStr for PgCircle {
+    type Err = GeometryParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse("circle", s, |p| {
+            p.skip_spaces();
+            let mut depth = 0;
+            if p.eat(b'<') {
+                depth += 1;
+            } else if p.peek() == Some(b'(') {
+                // If there are two left parens, consume the first one.
+                let cp = p.after_spaces(p.pos + 1);
+                if p.input.get(cp) == Some(&b'(') {
+                    depth += 1;
+                    p.pos = cp;
+                }
+            }
+            let center = p.pair()?;
+            p.eat(b',');
+            let radius = p.single()?;
+            // NaN is accepted.
+            if radius < 0.0 {
+                return None;
+            }
+            while depth > 0 {
+                if p.eat(b')') || (depth == 1 && p.eat(b'>')) {
+                    depth -= 1;
+                    p.skip_spaces();
+                } else {
+                    return None;
+                }
+            }
+            Some(PgCircle(center, radius))
+        })
+    }
+}
+
+/// Accepts `[(x1,y1),(x2,y2)]`, `((x1,y1),(x2,y2))`, `(x1,y1),(x2,y2)` or `x1,y1,x2,y2`.
+impl FromStr for PgLineSegment {
+    type Err = GeometryParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse("lseg", s, |p| {
+            let (points, _) = p.points(true, 2)?;
+            Some(PgLineSegment(points[0], points[1]))
+        })
+    }
+}
+
+/// Accepts the coefficients `{A,B,C}`, which must not both be zero for `A` and `B`, or two
+/// distinct points on the line in any of the forms accepted for [`PgLineSegment`].
+impl FromStr for PgLine {
+    type Err = GeometryParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse("line", s, |p| {
+            p.skip_spaces();
+            if p.eat(b'{') {
+                let a = p.single()?;
+                if !p.eat(b',') {
+                    return None;
+                }
+                let b = p.single()?;
+                if !p.eat(b',') {
+                    return None;
+                }
+                let c = p.single()?;
+                if !p.eat(b'}') || (fp_zero(a) && fp_zero(b)) {
+                    return None;
+                }
+                p.skip_spaces();
+                Some(PgLine { a, b, c })
+            } else {
+                let (points, _) = p.points(true, 2)?;
+                PgLine::from_points(points[0], points[1])
+            }
+        })
+    }
+}
+
+/// Accepts `[(x1,y1),...]` for an open path, or `((x1,y1),...)`, `(x1,y1),...`, `(x1,y1,...)` or
+/// `x1,y1,...` for a closed one.
+impl FromStr for PgPath {
+    type Err = GeometryParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse("path", s, |p| {
+            let num_points = pair_count(s)?;
+            p.skip_spaces();
+            // Skip a single leading paren.
+            let skipped_paren = p.at_last_paren();
+            if skipped_paren {
+                p.pos += 1;
+            }
+            let (points, is_open) = p.points(true, num_points)?;
+            if skipped_paren {
+                if !p.eat(b')') {
+                    return None;
+                }
+                p.skip_spaces();
+            }
+            Some(PgPath {
+                closed: !is_open,
+                points,
+            })
+        })
+    }
+}
+
+/// Accepts `((x1,y1),...)`, `(x1,y1),...`, `(x1,y1,...)` or `x1,y1,...`.
+impl FromStr for PgPolygon {
+    type Err = GeometryParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse("polygon", s, |p| {
+            let num_points = pair_count(s)?;
+            let (points, _) = p.points(false, num_points)?;
+            Some(PgPolygon(points))
+        })
+    }
+}
+
+// Formats a coordinate the same as `float8out`: the shortest representation which reads back as
+// the same number, switching to exponential notation for very large and small magnitudes.
+struct Float(f64);
+
+impl fmt::Display for Float {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = self.0;
+        if value.is_nan() {
+            return f.write_str("NaN");
+        } else if value.is_infinite() {
+            return f.write_str(if value > 0.0 { "Infinity" } else { "-Infinity" });
+        }
+        let exponential = format!("{:e}", value);
+        let (mantissa, exponent) = exponential.split_at(exponential.find('e').unwrap());
+        let exponent = exponent[1..].parse::<i32>().unwrap();
+        if !(-4..15).contains(&exponent) {
+            let sign = if exponent < 0 { '-' } else { '+' };
+            write!(f, "{}e{}{:02}", mantissa, sign, exponent.abs())
+        } else {
+            write!(f, "{}", value)
+        }
+    }
+}
+
+fn write_points(f: &mut fmt::Formatter, points: &[PgPoint]) -> fmt::Result {
+    for (i, point) in points.iter().enumerate() {
+        if i > 0 {
+            f.write_str(",")?;
+        }
+        write!(f, "{}", point)?;
+    }
+    Ok(())
+}
+
+/// Formats as `(x,y)`.
+impl fmt::Display for PgPoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({},{})", Float(self.0), Float(self.1))
+    }
+}
+
+/// Formats as `(x2,y2),(x1,y1)`, the upper right corner followed by the lower left one.
+impl fmt::Display for PgBox {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.high(), self.low())
+    }
+}
+
+/// Formats as `<(x,y),r>`.
+impl fmt::Display for PgCircle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "<{},{}>", self.0, Float(self.1))
+    }
+}
+
+/// Formats as `[(x1,y1),(x2,y2)]`.
+impl fmt::Display for PgLineSegment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{},{}]", self.0, self.1)
+    }
+}
+
+/// Formats as `{A,B,C}`.
+impl fmt::Display for PgLine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{{{},{},{}}}", Float(self.a), Float(self.b), Float(self.c))
+    }
+}
+
+/// Formats as `((x1,y1),...)` when closed, or `[(x1,y1),...]` when open.
+impl fmt::Display for PgPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (open, close) = if self.closed { ("(", ")") } else { ("[", "]") };
+        f.write_str(open)?;
+        write_points(f, &self.points)?;
+        f.write_str(close)
+    }
+}
+
+/// Formats as `((x1,y1),...)`.
+impl fmt::Display for PgPolygon {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("(")?;
+        write_points(f, &self.0)?;
+        f.write_str(")")
+    }
+}
+
+// Results of queries run with `sql_query` can be read from text columns.
+macro_rules! from_text {
+    ($($ty:ty),+) => {$(
+        impl FromSql<Text, Pg> for $ty {
+            fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
+                let text = str::from_utf8(not_none!(bytes))?;
+                Ok(text.parse()?)
+            }
+        }
+    )+};
+}
+
+from_text!(PgPoint, PgBox, PgCircle, PgLineSegment, PgLine, PgPath, PgPolygon);
+
+#[cfg(test)]
+mod tests {
+    use diesel::dsl::sql;
+    use diesel::prelude::*;
+    use diesel::result::Error;
+    use diesel::sql_types::Text;
+    use diesel::{select, sql_query};
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    use super::GeometryParseError;
+    use pg::types::geometric::{PgBox, PgCircle, PgLine, PgLineSegment, PgPath, PgPoint, PgPolygon};
+    use test_helpers::{connection, TestConnection};
+
+    // The text Postgres prints after reading `input` as `type_name`, or `None` if it rejects it.
+    fn postgres_text(connection: &TestConnection, type_name: &str, input: &str) -> Option<String> {
+        let query = format!("'{}'::{}::text", input.replace('\'', "''"), type_name);
+        // Run in a savepoint, so that a rejected input doesn't abort the test transaction.
+        connection
+            .transaction::<_, Error, _>(|| select(sql::<Text>(&query)).get_result(connection))
+            .ok()
+    }
+
+    fn check_matches_postgres<T>(type_name: &str, inputs: &[&str])
+    where
+        T: FromStr + Display,
+    {
+        let connection = connection();
+        for input in inputs {
+            let parsed = input.parse::<T>().ok().map(|value| value.to_string());
+            let expected = postgres_text(&connection, type_name, input);
+            assert_eq!(expected, parsed, "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn point_text_matches_postgres() {
+        check_matches_postgres::<PgPoint>(
+            "point",
+            &[
+                "(1,2)",
+                "1,2",
+                " ( 1 , 2 ) ",
+                "(1.5e3,-2.25E-7)",
+                "(.5,5.)",
+                "(+1,-0)",
+                "(1e15,123456789012345)",
+                "(0.0001,0.00001)",
+                "(0.1,1.7976931348623157e308)",
+                "(NaN,Infinity)",
+                "(-inf,-Infinity)",
+                "(nan,+INF)",
+                "(1e400,0)",
+                "(1e-400,0)",
+                "(5e-324,0)",
+                "(1e,2)",
+                "(1,2",
+                "1,2)",
+                "(1 2)",
+                "((1,2))",
+                "(1,2)x",
+                "(1,2),",
+                "(,2)",
+                "(.,2)",
+                "",
+            ],
+        );
+    }
+
+    #[test]
+    fn box_text_matches_postgres() {
+        check_matches_postgres::<PgBox>(
+            "box",
+            &[
+                "((0,0),(1,1))",
+                "(0,0),(1,1)",
+                "(1,1),(0,0)",
+                "((3,1),(1,4))",
+                "0,0,1,1",
+                "(0,0,1,1)",
+                " ( ( 0 , 0 ) , ( 1 , 1 ) ) ",
+                "((0,0)(1,1))",
+                "((3,1),(1,NaN))",
+                "[(0,0),(1,1)]",
+                "((0,0),(1,1)",
+                "((0,0),(1,1)))",
+                "(0,0),(1,1),(2,2)",
+                "(0,0)",
+                "<(0,0),(1,1)>",
+            ],
+        );
+    }
+
+    #[test]
+    fn circle_text_matches_postgres() {
+        check_matches_postgres::<PgCircle>(
+            "circle",
+            &[
+                "<(1,2),3>",
+                "((1,2),3)",
+                "(1,2),3",
+                "1,2,3",
+                " < ( 1 , 2 ) , 3 > ",
+                "<(1,2) 3>",
+                "((1,2),3>",
+                "<(1,2),NaN>",
+                "<(1,2),0>",
+                "<(1,2),-3>",
+                "<(1,2),3)",
+                "<(1,2),3",
+                "<1,2,3>",
+                "(1,2,3)",
+                "<<(1,2),3>>",
+                "((1,2),3))",
+            ],
+        );
+    }
+
+    #[test]
+    fn lseg_text_matches_postgres() {
+        check_matches_postgres::<PgLineSegment>(
+            "lseg",
+            &[
+                "[(0,0),(1,1)]",
+                "((0,0),(1,1))",
+                "(0,0),(1,1)",
+                "0,0,1,1",
+                "[0,0,1,1]",
+                "(0,0,1,1)",
+                "[(0,0),(1,1))",
+                "[(0,0),(1,1)",
+                "((0,0),(1,1)]",
+                "[(0,0),(1,1)]]",
+                "[(0,0)]",
+            ],
+        );
+    }
+
+    #[test]
+    fn line_text_matches_postgres() {
+        check_matches_postgres::<PgLine>(
+            "line",
+            &[
+                "{1,2,3}",
+                " { 1 , -1 , 0.5 } ",
+                "{0,1e-7,1}",
+                "{0,0,1}",
+                "{1,2}",
+                "{1,2,3",
+                "{1,2,3}x",
+                "[(0,0),(1,1)]",
+                "((1,2),(1,5))",
+                "((1,2),(3,2))",
+                "((1,2),(3,7))",
+                "0,0,2,3",
+                "(0,0),(0,0)",
+                "(0,0),(0,0.0000001)",
+            ],
+        );
+    }
+
+    #[test]
+    fn path_text_matches_postgres() {
+        check_matches_postgres::<PgPath>(
+            "path",
+            &[
+                "[(0,0),(1,1),(2,0)]",
+                "((0,0),(1,1),(2,0))",
+                "(0,0),(1,1)",
+                "(0,0,1,1)",
+                "0,0,1,1",
+                "[0,0,1,1]",
+                "(1,2)",
+                "((1,2))",
+                "[(1,2)]",
+                "1,2",
+                " [ ( 0 , 0 ) , ( 1 , 1 ) ] ",
+                "[(0,0),(1,1)",
+                "(0,0),(1,1))",
+                "[(0,0),(1,1))",
+                "((0,0),(1,1)]",
+                "((0,0),(1,1),)",
+                "(0,0),(1,1),(2",
+                "",
+                "[]",
+            ],
+        );
+    }
+
+    #[test]
+    fn polygon_text_matches_postgres() {
+        check_matches_postgres::<PgPolygon>(
+            "polygon",
+            &[
+                "((0,0),(1,1),(2,0))",
+                "(0,0),(1,1),(2,0)",
+                "(0,0,1,1,2,0)",
+                "0,0,1,1,2,0",
+                "(1,2)",
+                "((1,2))",
+                "[(0,0),(1,1)]",
+                "((0,0),(1,1)",
+                "((0,0),(1,1)))",
+                "",
+            ],
+        );
+    }
+
+    #[test]
+    fn parse_error_describes_input() {
+        let error = "(1,2".parse::<PgPoint>().unwrap_err();
+        assert_eq!(
+            GeometryParseError {
+                type_name: "point",
+                input: "(1,2".to_owned(),
+            },
+            error
+        );
+        assert_eq!(
+            "invalid input syntax for type point: \"(1,2\"",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn text_query_results() {
+        let connection = connection();
+        #[derive(QueryableByName, Debug, PartialEq)]
+        struct Shapes {
+            #[sql_type = "Text"]
+            point: PgPoint,
+            #[sql_type = "Text"]
+            boxes: PgBox,
+            #[sql_type = "Text"]
+            circle: PgCircle,
+            #[sql_type = "Text"]
+            lseg: PgLineSegment,
+            #[sql_type = "Text"]
+            line: PgLine,
+            #[sql_type = "Text"]
+            path: PgPath,
+            #[sql_type = "Text"]
+            polygon: PgPolygon,
+        }
+        let shapes = sql_query(
+            "SELECT point(1, 2)::text AS point,
+                box(point(3, 4), point(1, 2))::text AS boxes,
+                circle(point(1, 2), 3)::text AS circle,
+                lseg(point(1, 2), point(3, 4))::text AS lseg,
+                line(point(0, 0), point(1, 1))::text AS line,
+                popen(path '(0,0),(1,1)')::text AS path,
+                polygon '(0,0),(1,1),(2,0)'::text AS polygon",
+        ).get_result::<Shapes>(&connection);
+        let expected = Shapes {
+            point: PgPoint(1., 2.),
+            boxes: PgBox(PgPoint(1., 2.), PgPoint(3., 4.)),
+            circle: PgCircle(PgPoint(1., 2.), 3.),
+            lseg: PgLineSegment(PgPoint(1., 2.), PgPoint(3., 4.)),
+            line: PgLine {
+                a: 1.,
+                b: -1.,
+                c: 0.,
+            },
+            path: PgPath {
+                closed: false,
+                points: vec![PgPoint(0., 0.), PgPoint(1., 1.)],
+            },
+            polygon: PgPolygon(vec![PgPoint(0., 0.), PgPoint(1., 1.), PgPoint(2., 0.)]),
+        };
+        assert_eq!(Ok(expected), shapes);
+
+        let point = select(sql::<Text>("'(1,2)'")).get_result::<PgPoint>(&connection);
+        assert_eq!(Ok(PgPoint(1., 2.)), point);
+        let point = select(sql::<Text>("'not a point'")).get_result::<PgPoint>(&connection);
+        assert!(point.is_err());
+    }
+
+    #[test]
+    fn binary_results_still_decode() {
+        table! {
+            use sql_types::Point;
+            use diesel::sql_types::*;
+            text_binary_points {
+                id -> Integer,
+                location -> Point,
+            }
+        }
+        let connection = connection();
+        connection
+            .execute(
+                "CREATE TABLE text_binary_points (
+            id SERIAL PRIMARY KEY,
+            location POINT NOT NULL
+        )",
+            ).unwrap();
+        connection
+            .execute("INSERT INTO text_binary_points (location) VALUES (point(1.5, -2))")
+            .unwrap();
+        let point = text_binary_points::table
+            .select(text_binary_points::location)
+            .get_result::<PgPoint>(&connection);
+        assert_eq!(Ok(PgPoint(1.5, -2.)), point);
+    }
+}
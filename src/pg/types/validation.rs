@@ -0,0 +1,360 @@
+//! Checks that geometric values are ones Postgres can meaningfully store.
+//!
+//! Postgres accepts NaN and infinite coordinates in most geometric types, but the operators and
+//! functions then return meaningless results. A circle with a negative radius, a line whose `A`
+//! and `B` coefficients are both zero, and a path or polygon without any points are rejected when
+//! they are received, which only shows up as a runtime error from the database.
+//!
+//! When compiled with `features = ["validation"]`, the `ToSql` impls of all geometric types call
+//! [`Validate::validate`] and refuse to send invalid values. The fallible constructors such as
+//! [`PgCircle::try_new`] enforce the same rules regardless of the feature.
+
+use std::error::Error;
+use std::fmt;
+
+use super::geo_ops::fp_zero;
+use super::geometric::{PgBox, PgCircle, PgLine, PgLineSegment, PgPath, PgPoint, PgPolygon};
+
+/// The reason a geometric value failed [`Validate::validate`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GeometryValidationError {
+    /// A coordinate, coefficient or radius was NaN or infinite.
+    NonFinite {
+        /// The name of the SQL type being validated.
+        type_name: &'static str,
+        /// The offending value.
+        value: f64,
+    },
+    /// A circle had a radius less than zero.
+    NegativeRadius {
+        /// The offending radius.
+        radius: f64,
+    },
+    /// A line had `A` and `B` coefficients which were both zero, within the tolerance Postgres
+    /// uses.
+    DegenerateLine,
+    /// A path or polygon had no points.
+    NoPoints {
+        /// The name of the SQL type being validated.
+        type_name: &'static str,
+    },
+}
+
+impl fmt::Display for GeometryValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            GeometryValidationError::NonFinite { type_name, value } => write!(
+                f,
+                "Invalid {} value: {} is not a finite number",
+                type_name, value
+            ),
+            GeometryValidationError::NegativeRadius { radius } => write!(
+                f,
+                "Invalid circle value: the radius {} is negative",
+                radius
+            ),
+            GeometryValidationError::DegenerateLine => {
+                write!(f, "Invalid line value: A and B cannot both be zero")
+            }
+            GeometryValidationError::NoPoints { type_name } => {
+                write!(f, "Invalid {} value: it has no points", type_name)
+            }
+        }
+    }
+}
+
+impl Error for GeometryValidationError {}
+
+/// Validation of geometric values before they are sent to Postgres.
+///
+/// ```rust
+/// # extern crate diesel_geometry;
+/// # use diesel_geometry::data_types::{PgCircle, PgPoint, Validate};
+/// # fn main() {
+/// assert!(PgCircle(PgPoint(0.0, 0.0), 3.0).validate().is_ok());
+/// assert!(PgCircle(PgPoint(0.0, 0.0), -3.0).validate().is_err());
+/// assert!(PgPoint(f64::NAN, 0.0).validate().is_err());
+/// # }
+/// ```
+pub trait Validate {
+    /// Returns an error describing the first invalid part of `self`, if any.
+    fn validate(&self) -> Result<(), GeometryValidationError>;
+}
+
+fn check_finite<I>(type_name: &'static str, values: I) -> Result<(), GeometryValidationError>
+where
+    I: IntoIterator<Item = f64>,
+{
+    match values.into_iter().find(|value| !value.is_finite()) {
+        Some(value) => Err(GeometryValidationError::NonFinite { type_name, value }),
+        None => Ok(()),
+    }
+}
+
+fn check_points(
+    type_name: &'static str,
+    points: &[PgPoint],
+) -> Result<(), GeometryValidationError> {
+    check_finite(type_name, points.iter().flat_map(|p| Some(p.0).into_iter().chain(Some(p.1))))
+}
+
+fn check_not_empty(
+    type_name: &'static str,
+    points: &[PgPoint],
+) -> Result<(), GeometryValidationError> {
+    if points.is_empty() {
+        return Err(GeometryValidationError::NoPoints { type_name });
+    }
+    Ok(())
+}
+
+impl Validate for PgPoint {
+    fn validate(&self) -> Result<(), GeometryValidationError> {
+        check_points("point", &[*self])
+    }
+}
+
+impl Validate for PgBox {
+    fn validate(&self) -> Result<(), GeometryValidationError> {
+        check_points("box", &[self.0, self.1])
+    }
+}
+
+impl Validate for PgCircle {
+    fn validate(&self) -> Result<(), GeometryValidationError> {
+        check_points("circle", &[self.0])?;
+        check_finite("circle", Some(self.1))?;
+        if self.1 < 0.0 {
+            return Err(GeometryValidationError::NegativeRadius { radius: self.1 });
+        }
+        Ok(())
+    }
+}
+
+impl Validate for PgLineSegment {
+    fn validate(&self) -> Result<(), GeometryValidationError> {
+        check_points("lseg", &[self.0, self.1])
+    }
+}
+
+impl Validate for PgLine {
+    fn validate(&self) -> Result<(), GeometryValidationError> {
+        check_finite("line", [self.a, self.b, self.c].iter().cloned())?;
+        if fp_zero(self.a) && fp_zero(self.b) {
+            return Err(GeometryValidationError::DegenerateLine);
+        }
+        Ok(())
+    }
+}
+
+impl Validate for PgPath {
+    fn validate(&self) -> Result<(), GeometryValidationError> {
+        check_not_empty("path", &self.points)?;
+        check_points("path", &self.points)
+    }
+}
+
+impl Validate for PgPolygon {
+    fn validate(&self) -> Result<(), GeometryValidationError> {
+        check_not_empty("polygon", &self.0)?;
+        check_points("polygon", &self.0)
+    }
+}
+
+impl PgPoint {
+    /// Constructs the point `(x, y)`, or returns an error if either coordinate is not finite.
+    pub fn try_new(x: f64, y: f64) -> Result<Self, GeometryValidationError> {
+        let point = PgPoint(x, y);
+        point.validate()?;
+        Ok(point)
+    }
+}
+
+impl PgBox {
+    /// The same as [`PgBox::new`], but returns an error if any coordinate is not finite.
+    pub fn try_new(a: PgPoint, b: PgPoint) -> Result<Self, GeometryValidationError> {
+        PgBox(a, b).validate()?;
+        Ok(PgBox::new(a, b))
+    }
+}
+
+impl PgCircle {
+    /// Constructs a circle, or returns an error if any coordinate or the radius is not finite, or
+    /// the radius is negative.
+    ///
+    /// ```rust
+    /// # extern crate diesel_geometry;
+    /// # use diesel_geometry::data_types::{PgCircle, PgPoint};
+    /// # fn main() {
+    /// assert_eq!(
+    ///     Ok(PgCircle(PgPoint(1.0, 2.0), 3.0)),
+    ///     PgCircle::try_new(PgPoint(1.0, 2.0), 3.0)
+    /// );
+    /// let error = PgCircle::try_new(PgPoint(1.0, 2.0), -3.0).unwrap_err();
+    /// assert_eq!("Invalid circle value: the radius -3 is negative", error.to_string());
+    /// # }
+    /// ```
+    pub fn try_new(center: PgPoint, radius: f64) -> Result<Self, GeometryValidationError> {
+        let circle = PgCircle(center, radius);
+        circle.validate()?;
+        Ok(circle)
+    }
+}
+
+impl PgLineSegment {
+    /// Constructs the line segment from `start` to `end`, or returns an error if any coordinate is
+    /// not finite.
+    pub fn try_new(start: PgPoint, end: PgPoint) -> Result<Self, GeometryValidationError> {
+        let lseg = PgLineSegment(start, end);
+        lseg.validate()?;
+        Ok(lseg)
+    }
+}
+
+impl PgLine {
+    /// Constructs the line `Ax + By + C = 0`, or returns an error if any coefficient is not
+    /// finite, or `A` and `B` are both zero.
+    pub fn try_new(a: f64, b: f64, c: f64) -> Result<Self, GeometryValidationError> {
+        let line = PgLine { a, b, c };
+        line.validate()?;
+        Ok(line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use diesel::pg::Pg;
+    use diesel::serialize::ToSql;
+
+    use super::{GeometryValidationError, Validate};
+    use pg::types::geometric::{PgBox, PgCircle, PgLine, PgLineSegment, PgPath, PgPoint, PgPolygon};
+    use sql_types::Circle;
+    use test_helpers::create_testing_output;
+
+    #[test]
+    fn rejects_non_finite_values() {
+        let origin = PgPoint(0., 0.);
+        for &value in &[f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let bad = PgPoint(1., value);
+            // NaN != NaN, so compare the formatted errors.
+            let expected =
+                |type_name| GeometryValidationError::NonFinite { type_name, value }.to_string();
+            let error =
+                |result: Result<(), GeometryValidationError>| result.unwrap_err().to_string();
+            assert_eq!(expected("point"), error(bad.validate()));
+            assert_eq!(expected("box"), error(PgBox(origin, bad).validate()));
+            assert_eq!(expected("circle"), error(PgCircle(bad, 1.).validate()));
+            assert_eq!(expected("circle"), error(PgCircle(origin, value).validate()));
+            assert_eq!(expected("lseg"), error(PgLineSegment(bad, origin).validate()));
+            let line = PgLine {
+                a: 1.,
+                b: 0.,
+                c: value,
+            };
+            assert_eq!(expected("line"), error(line.validate()));
+            let path = PgPath {
+                closed: false,
+                points: vec![origin, bad],
+            };
+            assert_eq!(expected("path"), error(path.validate()));
+            assert_eq!(expected("polygon"), error(PgPolygon(vec![origin, bad]).validate()));
+
+            assert!(PgPoint::try_new(1., value).is_err());
+            assert!(PgBox::try_new(bad, origin).is_err());
+            assert!(PgCircle::try_new(origin, value).is_err());
+            assert!(PgLineSegment::try_new(origin, bad).is_err());
+            assert!(PgLine::try_new(value, 1., 0.).is_err());
+        }
+    }
+
+    #[test]
+    fn rejects_negative_radius() {
+        let origin = PgPoint(0., 0.);
+        assert_eq!(
+            Err(GeometryValidationError::NegativeRadius { radius: -3. }),
+            PgCircle::try_new(origin, -3.)
+        );
+        assert_eq!(Ok(PgCircle(origin, 0.)), PgCircle::try_new(origin, 0.));
+        assert_eq!(
+            Ok(PgBox(PgPoint(0., 0.), PgPoint(1., 2.))),
+            PgBox::try_new(PgPoint(1., 0.), PgPoint(0., 2.))
+        );
+    }
+
+    #[test]
+    fn rejects_values_postgres_does_not_receive() {
+        assert_eq!(
+            Err(GeometryValidationError::DegenerateLine),
+            PgLine::try_new(0., 0., 1.)
+        );
+        assert_eq!(
+            Err(GeometryValidationError::DegenerateLine),
+            PgLine::try_new(0.5E-06, -0.5E-06, 1.)
+        );
+        assert!(PgLine::try_new(0., 1.5E-06, 1.).is_ok());
+        let path = PgPath {
+            closed: true,
+            points: Vec::new(),
+        };
+        assert_eq!(
+            Err(GeometryValidationError::NoPoints { type_name: "path" }),
+            path.validate()
+        );
+        assert_eq!(
+            "Invalid polygon value: it has no points",
+            PgPolygon(Vec::new()).validate().unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "validation")]
+    fn to_sql_rejects_invalid_values() {
+        use sql_types::{self, Line, Lseg, Path, Point, Polygon};
+
+        let origin = PgPoint(0., 0.);
+        let bad = PgPoint(f64::NAN, 0.);
+        let mut bytes = create_testing_output();
+        let error = ToSql::<Circle, Pg>::to_sql(&PgCircle(origin, -3.), &mut bytes).unwrap_err();
+        assert_eq!("Invalid circle value: the radius -3 is negative", error.to_string());
+        let error = ToSql::<Point, Pg>::to_sql(&bad, &mut bytes).unwrap_err();
+        assert_eq!("Invalid point value: NaN is not a finite number", error.to_string());
+        assert!(ToSql::<sql_types::Box, Pg>::to_sql(&PgBox(bad, origin), &mut bytes).is_err());
+        assert!(ToSql::<Lseg, Pg>::to_sql(&PgLineSegment(bad, origin), &mut bytes).is_err());
+        let line = PgLine {
+            a: f64::INFINITY,
+            b: 1.,
+            c: 0.,
+        };
+        assert!(ToSql::<Line, Pg>::to_sql(&line, &mut bytes).is_err());
+        let line = PgLine {
+            a: 0.,
+            b: 0.,
+            c: 1.,
+        };
+        let error = ToSql::<Line, Pg>::to_sql(&line, &mut bytes).unwrap_err();
+        assert_eq!("Invalid line value: A and B cannot both be zero", error.to_string());
+        let path = PgPath {
+            closed: true,
+            points: vec![bad],
+        };
+        assert!(ToSql::<Path, Pg>::to_sql(&path, &mut bytes).is_err());
+        let path = PgPath {
+            closed: false,
+            points: Vec::new(),
+        };
+        let error = ToSql::<Path, Pg>::to_sql(&path, &mut bytes).unwrap_err();
+        assert_eq!("Invalid path value: it has no points", error.to_string());
+        assert!(ToSql::<Polygon, Pg>::to_sql(&PgPolygon(vec![bad]), &mut bytes).is_err());
+        let error = ToSql::<Polygon, Pg>::to_sql(&PgPolygon(Vec::new()), &mut bytes).unwrap_err();
+        assert_eq!("Invalid polygon value: it has no points", error.to_string());
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    #[cfg(not(feature = "validation"))]
+    fn to_sql_sends_invalid_values_without_validation() {
+        let mut bytes = create_testing_output();
+        let circle = PgCircle(PgPoint(f64::NAN, 0.), -3.);
+        assert!(ToSql::<Circle, Pg>::to_sql(&circle, &mut bytes).is_ok());
+    }
+}
@@ -23,9 +23,10 @@ cfg_if! {
         /// Returns a `Output` suitable for testing `ToSql` implementations.
         /// Unsafe to use for testing types which perform dynamic metadata lookup.
         pub fn create_testing_output<DB: TypeMetadata>() -> Output<'static, Vec<u8>, DB> {
-            use std::mem;
-            #[cfg_attr(feature = "clippy", allow(invalid_ref))]
-            Output::new(Vec::new(), unsafe { mem::uninitialized() })
+            use std::ptr::NonNull;
+            // The lookup is never read by the geometric types, but it must not be a null
+            // reference or recent compilers abort the test before it runs.
+            Output::new(Vec::new(), unsafe { &*NonNull::dangling().as_ptr() })
         }
     } else {
         compile_error!(